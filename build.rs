@@ -0,0 +1,265 @@
+//! Generate the tuple implementations of `rs_std_ext::tuple`.
+//!
+//! The highest supported arity depends on the enabled features:
+//! 5 by default, 10 with `long-tuple-impl`, 16 with `tuple-16` and 32 with `tuple-32`.
+//!
+//! Two files are written to `OUT_DIR`:
+//!
+//! - `tuple_impl.rs`, the macro invocations included by `src/tuple.rs`.
+//! - `tuple_impl_check.rs`, compile-time checks included by `tests/test_tuple_impls.rs`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+fn max_arity() -> usize {
+    let enabled = |feature: &str| env::var_os(format!("CARGO_FEATURE_{}", feature)).is_some();
+    if enabled("TUPLE_32") {
+        32
+    } else if enabled("TUPLE_16") {
+        16
+    } else if enabled("LONG_TUPLE_IMPL") {
+        10
+    } else {
+        5
+    }
+}
+
+/// `TupleConcat` has always been implemented for results up to 10 elements.
+fn concat_limit(max: usize) -> usize {
+    max.max(10)
+}
+
+/// `TupleSwapExact` needs an implementation for every pair of positions,
+/// so it is limited to tuples of up to 16 elements to keep the build fast.
+fn swap_limit(max: usize) -> usize {
+    max.min(16)
+}
+
+/// The type placeholders of a tuple of `len` elements.
+fn placeholders(len: usize) -> Vec<String> {
+    (0..len).map(|i| format!("T{}", i)).collect()
+}
+
+fn generate_impls(max: usize) -> String {
+    let mut out = String::new();
+    let mut emit = |line: String| {
+        out.push_str(&line);
+        out.push('\n');
+    };
+
+    for n in 1..=max {
+        let ph = placeholders(n).join(", ");
+        emit(format!("__impl_tuple_zip!({} | Z);", ph));
+        emit(format!("__impl_tuple_insert!({} | Z);", ph));
+        emit(format!("__impl_tuple_remove!({});", ph));
+        emit(format!("__impl_tuple_map!({});", ph));
+        emit(format!("__impl_tuple_map_nth!({} | M);", ph));
+        emit(format!("__impl_tuple_split!({});", ph));
+        emit(format!("__impl_tuple_rotate!({});", ph));
+        if n <= swap_limit(max) {
+            emit(format!("__impl_tuple_swap!({});", ph));
+        }
+        emit(format!("__impl_tuple_get!({});", ph));
+        emit(format!("__impl_tuple_replace!({} | Z);", ph));
+        emit(format!("__impl_tuple_array!({} => {});", ph, n));
+        emit(format!("__impl_tuple_apply!({});", ph));
+        emit(format!("__impl_tuple_transpose!({});", ph));
+    }
+
+    for n in 1..=max {
+        let ph = placeholders(n);
+        let list = ph.join(", ");
+
+        let reversed: Vec<_> = ph.iter().rev().cloned().collect();
+        emit(format!(
+            "__impl_tuple_reverse!({} => {});",
+            list,
+            reversed.join(", ")
+        ));
+        emit(format!(
+            "__impl_tuple_pop!({} | {}; {} | {}; {});",
+            list,
+            ph[0],
+            ph[1..].join(", "),
+            ph[..n - 1].join(", "),
+            ph[n - 1]
+        ));
+
+        for pos in 0..=n {
+            let mut inserted = ph.clone();
+            inserted.insert(pos, "Z".to_string());
+            emit(format!(
+                "__impl_tuple_insert_exact!({} | Z at {} => {});",
+                list,
+                pos,
+                inserted.join(", ")
+            ));
+            emit(format!(
+                "__impl_tuple_split_exact!({} at {} => {} | {});",
+                list,
+                pos,
+                ph[..pos].join(", "),
+                ph[pos..].join(", ")
+            ));
+
+            let mut left = ph[pos..].to_vec();
+            left.extend_from_slice(&ph[..pos]);
+            emit(format!(
+                "__impl_tuple_rotate_left!({} at {} => {});",
+                list,
+                pos,
+                left.join(", ")
+            ));
+            let mut right = ph[n - pos..].to_vec();
+            right.extend_from_slice(&ph[..n - pos]);
+            emit(format!(
+                "__impl_tuple_rotate_right!({} at {} => {});",
+                list,
+                pos,
+                right.join(", ")
+            ));
+        }
+
+        for pos in 0..n {
+            let mut removed = ph.clone();
+            removed.remove(pos);
+            emit(format!(
+                "__impl_tuple_remove_exact!({} at {} => {});",
+                list,
+                pos,
+                removed.join(", ")
+            ));
+
+            let mut replaced = ph.clone();
+            replaced[pos] = "Z".to_string();
+            emit(format!(
+                "__impl_tuple_map_nth_exact!({} | {} -> Z at {} => {});",
+                list,
+                ph[pos],
+                pos,
+                replaced.join(", ")
+            ));
+            emit(format!(
+                "__impl_tuple_get_exact!({} | {} at {} => {});",
+                list,
+                ph[pos],
+                pos,
+                replaced.join(", ")
+            ));
+
+            if n > swap_limit(max) {
+                continue;
+            }
+            for other in 0..n {
+                let mut swapped = ph.clone();
+                swapped.swap(pos, other);
+                emit(format!(
+                    "__impl_tuple_swap_exact!({} at {}, {} => {});",
+                    list,
+                    pos,
+                    other,
+                    swapped.join(", ")
+                ));
+            }
+        }
+    }
+
+    let limit = concat_limit(max);
+    for n in 1..=max.min(limit - 1) {
+        for m in 1..=limit - n {
+            let all = placeholders(n + m);
+            emit(format!(
+                "__impl_tuple_concat!({} with {});",
+                all[..n].join(", "),
+                all[n..].join(", ")
+            ));
+        }
+    }
+
+    out
+}
+
+/// Every position is filled with a distinct type, `[u8; i]`,
+/// so the checks also prove the order of the output.
+fn check_types(positions: impl IntoIterator<Item = usize>) -> String {
+    let types: Vec<_> = positions
+        .into_iter()
+        .map(|i| format!("[u8; {}]", i))
+        .collect();
+    format!("({},)", types.join(", ")).replace("(,)", "()")
+}
+
+fn generate_checks(max: usize) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// Generated by `build.rs` for tuples of up to {} elements.\n",
+        max
+    )
+    .unwrap();
+
+    out.push_str("#[test]\nfn test_tuple_insert_exact_impls() {\n");
+    for n in 1..=max {
+        for pos in 0..=n {
+            let mut output: Vec<_> = (0..n).collect();
+            output.insert(pos, n);
+            writeln!(
+                out,
+                "    insert_exact::<{}, {}, [u8; {}], {}>();",
+                check_types(0..n),
+                pos,
+                n,
+                check_types(output)
+            )
+            .unwrap();
+        }
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("#[test]\nfn test_tuple_remove_exact_impls() {\n");
+    for n in 1..=max {
+        for pos in 0..n {
+            let output = (0..n).filter(|&i| i != pos);
+            writeln!(
+                out,
+                "    remove_exact::<{}, {}, {}>();",
+                check_types(0..n),
+                pos,
+                check_types(output)
+            )
+            .unwrap();
+        }
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("#[test]\nfn test_tuple_concat_impls() {\n");
+    let limit = concat_limit(max);
+    for n in 1..=max.min(limit - 1) {
+        for m in 1..=limit - n {
+            writeln!(
+                out,
+                "    concat::<{}, {}, {}>();",
+                check_types(0..n),
+                check_types(n..n + m),
+                check_types(0..n + m)
+            )
+            .unwrap();
+        }
+    }
+    out.push_str("}\n");
+
+    out
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let max = max_arity();
+    let out_dir = env::var_os("OUT_DIR").expect("cargo sets OUT_DIR for build scripts");
+    let out_dir = Path::new(&out_dir);
+
+    fs::write(out_dir.join("tuple_impl.rs"), generate_impls(max)).unwrap();
+    fs::write(out_dir.join("tuple_impl_check.rs"), generate_checks(max)).unwrap();
+}
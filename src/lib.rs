@@ -16,7 +16,7 @@
 //! assert_eq! (
 //!     // this method is defined by the `OptionExt` trait
 //!     // Note: The method is deprecated. Use std's method instead.
-//!     op.is_none_or(|x| *x > 0),
+//!     OptionExt::is_none_or(&op, |x| *x > 0),
 //!     true
 //! );
 //! ```
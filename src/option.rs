@@ -61,8 +61,8 @@ pub trait OptionExt<T> {
     /// ```rust
     /// use rs_std_ext::option::OptionExt;
     /// 
-    /// assert_eq!(None::<usize>.is_none_or(|&i| i == 2), true);
-    /// assert_eq!(Some(5).is_none_or(|&i| i == 2), false);
+    /// assert_eq!(OptionExt::is_none_or(&None::<usize>, |&i| i == 2), true);
+    /// assert_eq!(OptionExt::is_none_or(&Some(5), |&i| i == 2), false);
     /// ```
     #[deprecated = "Use `Option::is_none_or` (offered by std) instead."]
     fn is_none_or(&self, f: impl FnOnce(&T) -> bool) -> bool;
@@ -2,6 +2,49 @@
 
 use std::time::Duration;
 
+mod backoff;
+mod clock;
+mod deadline;
+mod every;
+mod format;
+mod instant;
+mod interval;
+mod limiter;
+mod literal;
+mod parse;
+mod rate;
+mod round;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod signed;
+mod stats;
+mod stopwatch;
+mod system_time;
+mod time_of_day;
+mod unit;
+
+pub use backoff::{retry, retry_on, retry_with, Backoff, Jitter, JitterSource, SplitMix64};
+pub use clock::{Clock, ManualClock, SystemClock, Timestamp};
+pub use deadline::Deadline;
+pub use every::{Every, MissedTicks, Ticker, Upcoming};
+pub use format::{DurationDisplay, UnitStyle};
+pub use instant::InstantExt;
+pub use interval::{Interval, Steps, TimePoint};
+pub use limiter::{RateLimiter, TokenBucket};
+#[doc(hidden)]
+pub use literal::__duration_literal;
+pub use parse::{ParseDurationError, ParseDurationErrorKind};
+pub use rate::{Rate, RateNumExt};
+pub use round::{DurationParts, RoundingMode};
+pub use signed::{
+    NegativeDurationError, SignedDuration, SignedDurationNumExt, SignedDurationNumExtFallible,
+};
+pub use stats::DurationStats;
+pub use stopwatch::{time_it, time_it_on, Stopwatch, TimedGuard};
+pub use system_time::SystemTimeExt;
+pub use time_of_day::TimeOfDay;
+pub use unit::TimeUnit;
+
 /// Extension methods for constructing [`std::time::Duration`] with numbers.
 ///
 /// ## Note
@@ -123,6 +166,20 @@ pub trait DurationNumExt: DurationNumExtFallible {
     fn days(&self) -> Duration {
         DurationNumExtFallible::days(self).unwrap()
     }
+
+    /// Create a `Duration`, using the postfix syntax.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationNumExt;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(2u8.weeks(), Duration::from_secs(2u64 * 60 * 60 * 24 * 7));
+    /// ```
+    fn weeks(&self) -> Duration {
+        DurationNumExtFallible::weeks(self).unwrap()
+    }
 }
 
 /// Extension methods for constructing [`std::time::Duration`] with numbers.
@@ -228,19 +285,98 @@ pub trait DurationNumExtFallible {
     /// assert_eq!(10u128.days(), Some(Duration::from_secs(10u64 * 60 * 60 * 24)));
     /// ```
     fn days(&self) -> Option<Duration>;
+
+    /// Create a `Duration`, using the postfix syntax.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationNumExtFallible;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(2u128.weeks(), Some(Duration::from_secs(2u64 * 60 * 60 * 24 * 7)));
+    /// assert!(u64::MAX.weeks().is_none());
+    /// ```
+    fn weeks(&self) -> Option<Duration> {
+        self.days()
+            .and_then(|d| d.checked_mul((SECS_PER_WEEK / SECS_PER_DAY) as u32))
+    }
 }
 
 pub const SECS_PER_MINUTE: u64 = 60;
 pub const SECS_PER_HOUR: u64 = 60 * 60;
 pub const SECS_PER_DAY: u64 = 60 * 60 * 24;
+pub const SECS_PER_WEEK: u64 = 60 * 60 * 24 * 7;
 
 #[cfg(feature = "crate-num")]
 mod __num_impl {
     use std::time::Duration;
 
-    use num::{BigInt, BigUint, ToPrimitive};
+    use num::rational::Ratio;
+    use num::{BigInt, BigRational, BigUint, Integer, Signed, ToPrimitive};
+
+    use super::{
+        extfn, parse, DurationNumExtFallible, TimeUnit, SECS_PER_DAY, SECS_PER_HOUR,
+        SECS_PER_MINUTE,
+    };
+
+    /// Convert a ratio measured in `unit` to a `Duration`, truncating to the nanosecond.
+    fn ratio_to_duration(value: &BigRational, unit: TimeUnit) -> Option<Duration> {
+        if value.is_negative() {
+            return None;
+        }
+        let nanos = value.numer() * BigInt::from(unit.as_nanos()) / value.denom();
+        nanos.to_u128().and_then(parse::nanos_to_duration)
+    }
+
+    /// Exact implementation for rational numbers, e.g. [`num::BigRational`].
+    ///
+    /// The result is truncated to the nanosecond.
+    impl<T: Clone + Integer + Into<BigInt>> DurationNumExtFallible for Ratio<T> {
+        #[inline]
+        fn seconds(&self) -> Option<Duration> {
+            ratio_to_duration(&to_big(self), TimeUnit::Second)
+        }
+
+        #[inline]
+        fn microseconds(&self) -> Option<Duration> {
+            ratio_to_duration(&to_big(self), TimeUnit::Microsecond)
+        }
 
-    use super::{extfn, DurationNumExtFallible, SECS_PER_DAY, SECS_PER_HOUR, SECS_PER_MINUTE};
+        #[inline]
+        fn milliseconds(&self) -> Option<Duration> {
+            ratio_to_duration(&to_big(self), TimeUnit::Millisecond)
+        }
+
+        #[inline]
+        fn nanoseconds(&self) -> Option<Duration> {
+            ratio_to_duration(&to_big(self), TimeUnit::Nanosecond)
+        }
+
+        #[inline]
+        fn minutes(&self) -> Option<Duration> {
+            ratio_to_duration(&to_big(self), TimeUnit::Minute)
+        }
+
+        #[inline]
+        fn hours(&self) -> Option<Duration> {
+            ratio_to_duration(&to_big(self), TimeUnit::Hour)
+        }
+
+        #[inline]
+        fn days(&self) -> Option<Duration> {
+            ratio_to_duration(&to_big(self), TimeUnit::Day)
+        }
+
+        #[inline]
+        fn weeks(&self) -> Option<Duration> {
+            ratio_to_duration(&to_big(self), TimeUnit::Week)
+        }
+    }
+
+    fn to_big<T: Clone + Integer + Into<BigInt>>(value: &Ratio<T>) -> BigRational {
+        BigRational::new(value.numer().clone().into(), value.denom().clone().into())
+    }
 
     impl DurationNumExtFallible for BigUint {
         #[inline]
@@ -330,10 +466,12 @@ mod __num_impl {
 }
 
 mod __std_impl {
+    use std::cmp::Ordering;
     use std::time::Duration;
 
     use super::{
-        extfn, DurationNumExt, DurationNumExtFallible, SECS_PER_DAY, SECS_PER_HOUR, SECS_PER_MINUTE,
+        extfn, parse, DurationNumExt, DurationNumExtFallible, TimeUnit, SECS_PER_DAY,
+        SECS_PER_HOUR, SECS_PER_MINUTE,
     };
 
     macro_rules! __impl_non_fallible {
@@ -434,46 +572,121 @@ mod __std_impl {
     // theoretically infallible
     __impl_fallible_int!(u8, u16, u32, u64);
 
+    /// Convert a float measured in `unit` to a `Duration`,
+    /// rounding to the nearest nanosecond (ties to even).
+    fn float_to_duration(value: f64, unit: TimeUnit) -> Option<Duration> {
+        mul_nanos_f64(unit.as_nanos(), value)
+    }
+
+    /// Multiply `nanos` by `factor`, rounding to the nearest nanosecond (ties to even).
+    ///
+    /// The float is decomposed into `mantissa * 2^exp` and scaled with integers,
+    /// so no precision is lost by converting to seconds first.
+    ///
+    /// Returns `None` if the factor is negative or not finite,
+    /// or if the result overflows.
+    pub(super) fn mul_nanos_f64(nanos: u128, factor: f64) -> Option<Duration> {
+        if !factor.is_finite() || factor < 0.0 {
+            return None;
+        }
+
+        let bits = factor.to_bits();
+        let exp_bits = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exp) = if exp_bits == 0 {
+            (fraction as u128, -1074)
+        } else {
+            ((fraction | (1 << 52)) as u128, exp_bits - 1075)
+        };
+
+        // a duration has less than 2^94 nanoseconds and the mantissa has 53 bits,
+        // so the product (below 2^147) is split into `high * 2^64 + low`
+        const LOW_BITS: u128 = u64::MAX as u128;
+        let wide = (nanos & LOW_BITS) * mantissa;
+        let high = (nanos >> 64) * mantissa + (wide >> 64);
+        let low = wide & LOW_BITS;
+
+        let nanos = if exp >= 0 {
+            if high >> 64 != 0 {
+                return None;
+            }
+            let product = high << 64 | low;
+            if product != 0 && product.leading_zeros() <= exp as u32 {
+                return None;
+            }
+            product << exp
+        } else {
+            let shift = exp.unsigned_abs();
+            // the product shifted right by `shift`,
+            // and how the dropped bits compare to half of `2^shift`
+            let (quotient, dropped) = if shift > 147 {
+                (0, Ordering::Less)
+            } else if shift <= 64 {
+                if high != 0 && high.leading_zeros() < 64 - shift {
+                    return None;
+                }
+                let quotient = high << (64 - shift) | low >> shift;
+                let rest = low & ((1 << shift) - 1);
+                (quotient, rest.cmp(&(1 << (shift - 1))))
+            } else {
+                let shift = shift - 64;
+                let rest = high & ((1 << shift) - 1);
+                let dropped = rest.cmp(&(1 << (shift - 1))).then(low.cmp(&0));
+                (high >> shift, dropped)
+            };
+            let up = dropped == Ordering::Greater
+                || (dropped == Ordering::Equal && quotient % 2 == 1);
+            quotient.checked_add(up as u128)?
+        };
+
+        parse::nanos_to_duration(nanos)
+    }
+
     macro_rules! __impl_fallible_float {
-        ($($ty:ty: $ident:ident),*) => {
-            $(__impl_fallible_float!(@$ty, $ident);)*
+        ($($ty:ty),*) => {
+            $(__impl_fallible_float!(@$ty);)*
         };
 
-        (@$ty:ty, $ident:ident) => {
+        (@$ty:ty) => {
             impl DurationNumExtFallible for $ty {
                 #[inline]
                 fn seconds(&self) -> Option<Duration> {
-                    Duration::$ident(*self).ok()
+                    float_to_duration(*self as f64, TimeUnit::Second)
                 }
 
                 #[inline]
                 fn microseconds(&self) -> Option<Duration> {
-                    Duration::$ident(*self / 1_000_000.0).ok()
+                    float_to_duration(*self as f64, TimeUnit::Microsecond)
                 }
 
                 #[inline]
                 fn milliseconds(&self) -> Option<Duration> {
-                    Duration::$ident(*self / 1_000.0).ok()
+                    float_to_duration(*self as f64, TimeUnit::Millisecond)
                 }
 
                 #[inline]
                 fn nanoseconds(&self) -> Option<Duration> {
-                    Duration::$ident(*self / 1_000_000_000.0).ok()
+                    float_to_duration(*self as f64, TimeUnit::Nanosecond)
                 }
 
                 #[inline]
                 fn minutes(&self) -> Option<Duration> {
-                    Duration::$ident(*self * 60.0).ok()
+                    float_to_duration(*self as f64, TimeUnit::Minute)
                 }
 
                 #[inline]
                 fn hours(&self) -> Option<Duration> {
-                    Duration::$ident(*self * 3600.0).ok()
+                    float_to_duration(*self as f64, TimeUnit::Hour)
                 }
 
                 #[inline]
                 fn days(&self) -> Option<Duration> {
-                    Duration::$ident(*self * 86400.0).ok()
+                    float_to_duration(*self as f64, TimeUnit::Day)
+                }
+
+                #[inline]
+                fn weeks(&self) -> Option<Duration> {
+                    float_to_duration(*self as f64, TimeUnit::Week)
                 }
             }
 
@@ -481,7 +694,8 @@ mod __std_impl {
         };
     }
 
-    __impl_fallible_float!(f32: try_from_secs_f32, f64: try_from_secs_f64);
+    // `f32` converts to `f64` losslessly
+    __impl_fallible_float!(f32, f64);
 }
 
 pub const NANOS_PER_SEC: u32 = 1_000_000_000;
@@ -494,57 +708,46 @@ pub const MICROS_PER_SEC: u64 = 1_000_000;
 pub mod extfn {
     use std::time::Duration;
 
-    use super::{MICROS_PER_SEC, MILLIS_PER_SEC, NANOS_PER_MICRO, NANOS_PER_MILLI, NANOS_PER_SEC};
+    use super::{
+        parse, ParseDurationError, MICROS_PER_SEC, MILLIS_PER_SEC, NANOS_PER_MICRO,
+        NANOS_PER_MILLI, NANOS_PER_SEC,
+    };
 
     /// A checked version of [`Duration::from_secs`][duration_from_secs].
     ///
     /// [duration_from_secs]: std::time::Duration#method.from_secs
-    pub fn checked_from_secs(secs: u64) -> Option<Duration> {
+    pub const fn checked_from_secs(secs: u64) -> Option<Duration> {
         checked_new(secs, 0)
     }
 
     /// A checked version of [`Duration::from_millis`][duration_from_millis].
     ///
     /// [duration_from_millis]: std::time::Duration#method.from_millis
-    pub fn checked_from_millis(millis: u64) -> Option<Duration> {
-        millis
-            .checked_div(MILLIS_PER_SEC)
-            .zip(
-                millis
-                    .checked_rem(MILLIS_PER_SEC)
-                    .and_then(|r| <u64 as TryInto<u32>>::try_into(r).ok())
-                    .and_then(|r| r.checked_mul(NANOS_PER_MILLI)),
-            )
-            .and_then(|(x, y)| checked_new(x, y))
+    pub const fn checked_from_millis(millis: u64) -> Option<Duration> {
+        checked_new(
+            millis / MILLIS_PER_SEC,
+            (millis % MILLIS_PER_SEC) as u32 * NANOS_PER_MILLI,
+        )
     }
 
     /// A checked version of [`Duration::from_micros`][duration_from_micros].
     ///
     /// [duration_from_micros]: std::time::Duration#method.from_micros
-    pub fn checked_from_micros(micros: u64) -> Option<Duration> {
-        micros
-            .checked_div(MICROS_PER_SEC)
-            .zip(
-                micros
-                    .checked_rem(MICROS_PER_SEC)
-                    .and_then(|r| <u64 as TryInto<u32>>::try_into(r).ok())
-                    .and_then(|r| r.checked_mul(NANOS_PER_MICRO)),
-            )
-            .and_then(|(x, y)| checked_new(x, y))
+    pub const fn checked_from_micros(micros: u64) -> Option<Duration> {
+        checked_new(
+            micros / MICROS_PER_SEC,
+            (micros % MICROS_PER_SEC) as u32 * NANOS_PER_MICRO,
+        )
     }
 
     /// A checked version of [`Duration::from_nanos`][duration_from_nanos].
     ///
     /// [duration_from_nanos]: std::time::Duration#method.from_nanos
-    pub fn checked_from_nanos(nanos: u64) -> Option<Duration> {
-        nanos
-            .checked_div(NANOS_PER_SEC as u64)
-            .zip(
-                nanos
-                    .checked_rem(NANOS_PER_SEC as u64)
-                    .and_then(|r| <u64 as TryInto<u32>>::try_into(r).ok()),
-            )
-            .and_then(|(x, y)| checked_new(x, y))
+    pub const fn checked_from_nanos(nanos: u64) -> Option<Duration> {
+        checked_new(
+            nanos / NANOS_PER_SEC as u64,
+            (nanos % NANOS_PER_SEC as u64) as u32,
+        )
     }
 
     /// A pre-checked version of [`Duration::new`][duration_new].
@@ -554,16 +757,493 @@ pub mod extfn {
     /// might still panics. However, this method uplifts the check,
     /// and theoretically never panics.
     ///
+    /// All the `checked_*` functions are `const`, so they can be used
+    /// to build `Duration` constants:
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::extfn;
+    /// use std::time::Duration;
+    ///
+    /// const TIMEOUT: Option<Duration> = extfn::checked_new(90, 1_500_000_000);
+    /// assert_eq!(TIMEOUT, Some(Duration::from_millis(91_500)));
+    /// assert_eq!(extfn::checked_new(u64::MAX, 1_000_000_000), None);
+    /// ```
+    ///
     /// [duration_new]: std::time::Duration#method.new
-    pub fn checked_new(secs: u64, nanos: u32) -> Option<Duration> {
-        let secs = match secs.checked_add((nanos / NANOS_PER_SEC) as u64) {
-            Some(secs) => secs,
-            None => return None,
-        };
-        let nanos = nanos % NANOS_PER_SEC;
-        Some(Duration::new(secs, nanos))
+    pub const fn checked_new(secs: u64, nanos: u32) -> Option<Duration> {
+        match secs.checked_add((nanos / NANOS_PER_SEC) as u64) {
+            Some(secs) => Some(Duration::new(secs, nanos % NANOS_PER_SEC)),
+            None => None,
+        }
+    }
+
+    /// Parse a `Duration` from a string.
+    ///
+    /// The following formats are accepted, surrounding whitespace is ignored:
+    ///
+    /// - Compound units, e.g. `1h 30m 15.25s`, `1h30m` or `2 days 3 hours`.
+    ///   The units are the ones offered by [`DurationNumExt`](super::DurationNumExt),
+    ///   namely `ns`, `us`/`µs`, `ms`, `s`, `m`, `h`, `d` and `w`,
+    ///   as well as their long names (`sec`, `minutes`, ...).
+    ///   Each unit may appear only once.
+    /// - ISO 8601 durations without calendar units, e.g. `P1DT2H3M4.5S`, `P2W` or `PT90M`.
+    ///   Only the last component may have a fraction.
+    /// - Clock forms, e.g. `01:02:03.250` (`HH:MM:SS`), `02:03` (`MM:SS`)
+    ///   or `1:02:03:04` (`D:HH:MM:SS`).
+    ///   Every field but the first must have two digits.
+    ///
+    /// A decimal point must be followed by at least one digit.
+    ///
+    /// Unlike the other functions in this module, this function returns
+    /// a [`ParseDurationError`] describing what went wrong and where.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::extfn::parse_duration;
+    /// use rs_std_ext::time::ParseDurationErrorKind;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(parse_duration("1h 30m 15.25s"), Ok(Duration::from_millis(5_415_250)));
+    /// assert_eq!(parse_duration("PT1H30M"), Ok(Duration::from_secs(5_400)));
+    /// assert_eq!(parse_duration("01:30:00.5"), Ok(Duration::from_millis(5_400_500)));
+    ///
+    /// let err = parse_duration("1h 30x").unwrap_err();
+    /// assert_eq!(err.kind(), &ParseDurationErrorKind::UnknownUnit("x".into()));
+    /// assert_eq!(err.position(), 5);
+    /// ```
+    pub fn parse_duration(s: &str) -> Result<Duration, ParseDurationError> {
+        parse::parse_duration(s)
+    }
+}
+
+/// Extension methods for parsing [`std::time::Duration`] from strings.
+///
+/// This works like [`str::parse`], since `FromStr` cannot be implemented
+/// for `Duration` outside the standard library.
+pub trait DurationFromStrExt {
+    /// Parse a `Duration` from a string.
+    ///
+    /// See [`extfn::parse_duration`] for the supported formats.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationFromStrExt;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!("1m 30s".parse_duration(), Ok(Duration::from_secs(90)));
+    /// assert!("30".parse_duration().is_err());
+    /// ```
+    fn parse_duration(&self) -> Result<Duration, ParseDurationError>;
+}
+
+impl DurationFromStrExt for str {
+    fn parse_duration(&self) -> Result<Duration, ParseDurationError> {
+        extfn::parse_duration(self)
     }
 }
 
 /// Extension methods for [`std::time::Duration`].
-pub trait DurationExt {}
+pub trait DurationExt {
+    /// Format the duration in a human-readable form, e.g. `1d 2h 3m 4.5s`.
+    ///
+    /// By default, units from days to seconds are rendered,
+    /// with the exact fraction of a second.
+    /// See [`DurationDisplay`] for available options.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationExt, TimeUnit};
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(93_784, 500_000_000).display_human().to_string(), "1d 2h 3m 4.5s");
+    /// assert_eq!(Duration::ZERO.display_human().to_string(), "0s");
+    /// assert_eq!(
+    ///     Duration::from_micros(1_500)
+    ///         .display_human()
+    ///         .smallest_unit(TimeUnit::Millisecond)
+    ///         .to_string(),
+    ///     "1.5ms"
+    /// );
+    /// ```
+    fn display_human(&self) -> DurationDisplay;
+
+    /// Format the duration as a clock, e.g. `26:03:04.500`.
+    ///
+    /// By default, hours are the largest unit and
+    /// the fraction of a second is rendered with 3 digits.
+    /// See [`DurationDisplay`] for available options.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::new(93_784, 500_000_000);
+    /// assert_eq!(d.display_compact().to_string(), "26:03:04.500");
+    /// assert_eq!(d.display_compact().precision(None).to_string(), "26:03:04.5");
+    /// ```
+    fn display_compact(&self) -> DurationDisplay;
+
+    /// Format the duration as an ISO 8601 duration, e.g. `P1DT2H3M4.5S`.
+    ///
+    /// By default, days are the largest unit.
+    /// See [`DurationDisplay`] for available options.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(93_784, 500_000_000).display_iso8601().to_string(), "P1DT2H3M4.5S");
+    /// assert_eq!(Duration::ZERO.display_iso8601().to_string(), "PT0S");
+    /// ```
+    fn display_iso8601(&self) -> DurationDisplay;
+
+    /// Round the duration to a multiple of `unit`, using the given rounding mode.
+    ///
+    /// The unit can be a [`TimeUnit`] or any `Duration`, e.g. `15 minutes`.
+    /// A zero unit leaves the duration unchanged.
+    ///
+    /// Returns `None` if the result overflows.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationExt, RoundingMode, TimeUnit};
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::from_millis(2_500);
+    /// assert_eq!(d.round_with(TimeUnit::Second, RoundingMode::HalfUp), Some(Duration::from_secs(3)));
+    /// assert_eq!(d.round_with(TimeUnit::Second, RoundingMode::HalfEven), Some(Duration::from_secs(2)));
+    /// assert_eq!(Duration::MAX.round_with(TimeUnit::Day, RoundingMode::AwayFromZero), None);
+    /// ```
+    fn round_with(&self, unit: impl Into<Duration>, mode: RoundingMode) -> Option<Duration>;
+
+    /// Round the duration to the nearest multiple of `unit`, with ties rounded up.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationExt, TimeUnit};
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_millis(1_500).round_to(TimeUnit::Second), Duration::from_secs(2));
+    /// assert_eq!(Duration::from_secs(500).round_to(Duration::from_secs(300)), Duration::from_secs(600));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the result overflows.
+    fn round_to(&self, unit: impl Into<Duration>) -> Duration {
+        self.round_with(unit, RoundingMode::HalfUp)
+            .expect("overflow when rounding duration")
+    }
+
+    /// Round the duration down to a multiple of `unit`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationExt, TimeUnit};
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_millis(1_999).floor_to(TimeUnit::Second), Duration::from_secs(1));
+    /// ```
+    fn floor_to(&self, unit: impl Into<Duration>) -> Duration {
+        // rounding down never overflows
+        self.round_with(unit, RoundingMode::TowardZero).unwrap()
+    }
+
+    /// Round the duration up to a multiple of `unit`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationExt, TimeUnit};
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_millis(1_001).ceil_to(TimeUnit::Second), Duration::from_secs(2));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the result overflows.
+    fn ceil_to(&self, unit: impl Into<Duration>) -> Duration {
+        self.round_with(unit, RoundingMode::AwayFromZero)
+            .expect("overflow when rounding duration")
+    }
+
+    /// Truncate the duration to whole seconds.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_millis(2_999).truncate_to_secs(), Duration::from_secs(2));
+    /// ```
+    fn truncate_to_secs(&self) -> Duration {
+        self.floor_to(TimeUnit::Second)
+    }
+
+    /// Truncate the duration to whole milliseconds.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_nanos(1_234_567).truncate_to_millis(), Duration::from_millis(1));
+    /// ```
+    fn truncate_to_millis(&self) -> Duration {
+        self.floor_to(TimeUnit::Millisecond)
+    }
+
+    /// Truncate the duration to whole microseconds.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_nanos(1_234_567).truncate_to_micros(), Duration::from_micros(1_234));
+    /// ```
+    fn truncate_to_micros(&self) -> Duration {
+        self.floor_to(TimeUnit::Microsecond)
+    }
+
+    /// Break the duration into days, hours, minutes, seconds,
+    /// milliseconds, microseconds and nanoseconds.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// let parts = Duration::new(93_784, 5_006_007).decompose();
+    /// assert_eq!(
+    ///     (parts.days, parts.hours, parts.minutes, parts.seconds),
+    ///     (1, 2, 3, 4)
+    /// );
+    /// assert_eq!((parts.millis, parts.micros, parts.nanos), (5, 6, 7));
+    /// ```
+    fn decompose(&self) -> DurationParts;
+
+    /// Multiply the duration by a float.
+    ///
+    /// The result is rounded to the nearest nanosecond (ties to even),
+    /// without converting the duration to a float first.
+    ///
+    /// Returns `None` if the factor is negative or not finite,
+    /// or if the result overflows.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::from_secs(2);
+    /// assert_eq!(d.checked_mul_f64(1.5), Some(Duration::from_secs(3)));
+    /// assert_eq!(d.checked_mul_f64(-1.0), None);
+    /// assert_eq!(Duration::MAX.checked_mul_f64(2.0), None);
+    /// ```
+    fn checked_mul_f64(&self, rhs: f64) -> Option<Duration>;
+
+    /// Multiply the duration by a float, rounding like [`DurationExt::checked_mul_f64`].
+    ///
+    /// Returns `Duration::MAX` if the result overflows,
+    /// and `Duration::ZERO` if the factor is negative or `NaN`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::from_secs(2);
+    /// assert_eq!(d.saturating_mul_f64(0.25), Duration::from_millis(500));
+    /// assert_eq!(d.saturating_mul_f64(f64::INFINITY), Duration::MAX);
+    /// assert_eq!(d.saturating_mul_f64(f64::NAN), Duration::ZERO);
+    /// ```
+    fn saturating_mul_f64(&self, rhs: f64) -> Duration;
+
+    /// Divide the duration by another one, returning the ratio as `f64`.
+    ///
+    /// Returns `None` if `rhs` is zero.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::from_secs(3);
+    /// assert_eq!(d.checked_div_duration(Duration::from_secs(2)), Some(1.5));
+    /// assert_eq!(d.checked_div_duration(Duration::ZERO), None);
+    /// ```
+    fn checked_div_duration(&self, rhs: Duration) -> Option<f64>;
+
+    /// Divide the duration by another one, returning the exact ratio.
+    ///
+    /// Returns `None` if `rhs` is zero.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use num::rational::Ratio;
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::from_secs(1);
+    /// assert_eq!(d.checked_div_duration_ratio(Duration::from_secs(3)), Some(Ratio::new(1, 3)));
+    /// ```
+    #[cfg(feature = "crate-num")]
+    fn checked_div_duration_ratio(&self, rhs: Duration) -> Option<num::rational::Ratio<u128>>;
+
+    /// Multiply the duration by `numer / denom` exactly, rounding down to the nanosecond.
+    ///
+    /// Returns `None` if `denom` is zero or the result overflows.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::from_secs(10);
+    /// assert_eq!(d.mul_ratio(1, 3), Some(Duration::new(3, 333_333_333)));
+    /// assert_eq!(Duration::MAX.mul_ratio(u64::MAX, u64::MAX), Some(Duration::MAX));
+    /// assert_eq!(d.mul_ratio(1, 0), None);
+    /// ```
+    fn mul_ratio(&self, numer: u64, denom: u64) -> Option<Duration>;
+
+    /// Linearly interpolate between `self` and `other`.
+    ///
+    /// `t` is clamped to `[0, 1]`, so the result always lies between the two
+    /// durations, and a `NaN` factor returns `self`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// let a = Duration::from_secs(10);
+    /// let b = Duration::from_secs(20);
+    /// assert_eq!(a.lerp(b, 0.25), Duration::from_millis(12_500));
+    /// assert_eq!(b.lerp(a, 0.25), Duration::from_millis(17_500));
+    /// assert_eq!(a.lerp(b, 2.0), b);
+    /// ```
+    fn lerp(&self, other: Duration, t: f64) -> Duration;
+
+    /// Restrict the duration to the interval between two bounds.
+    ///
+    /// Unlike [`Ord::clamp`], the bounds may be given in any order,
+    /// and this method never panics.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationExt;
+    /// use std::time::Duration;
+    ///
+    /// let lo = Duration::from_secs(1);
+    /// let hi = Duration::from_secs(5);
+    /// assert_eq!(Duration::from_secs(9).clamp_between(lo, hi), hi);
+    /// assert_eq!(Duration::ZERO.clamp_between(hi, lo), lo);
+    /// ```
+    fn clamp_between(&self, a: Duration, b: Duration) -> Duration;
+}
+
+impl DurationExt for Duration {
+    fn display_human(&self) -> DurationDisplay {
+        DurationDisplay::human(*self)
+    }
+
+    fn display_compact(&self) -> DurationDisplay {
+        DurationDisplay::compact(*self)
+    }
+
+    fn display_iso8601(&self) -> DurationDisplay {
+        DurationDisplay::iso8601(*self)
+    }
+
+    fn round_with(&self, unit: impl Into<Duration>, mode: RoundingMode) -> Option<Duration> {
+        round::round(*self, unit.into(), mode)
+    }
+
+    fn decompose(&self) -> DurationParts {
+        DurationParts::new(*self)
+    }
+
+    fn checked_mul_f64(&self, rhs: f64) -> Option<Duration> {
+        __std_impl::mul_nanos_f64(self.as_nanos(), rhs)
+    }
+
+    fn saturating_mul_f64(&self, rhs: f64) -> Duration {
+        if rhs.is_nan() || rhs <= 0.0 {
+            return Duration::ZERO;
+        }
+        self.checked_mul_f64(rhs).unwrap_or(Duration::MAX)
+    }
+
+    fn checked_div_duration(&self, rhs: Duration) -> Option<f64> {
+        if rhs.is_zero() {
+            None
+        } else {
+            Some(self.as_nanos() as f64 / rhs.as_nanos() as f64)
+        }
+    }
+
+    #[cfg(feature = "crate-num")]
+    fn checked_div_duration_ratio(&self, rhs: Duration) -> Option<num::rational::Ratio<u128>> {
+        if rhs.is_zero() {
+            None
+        } else {
+            Some(num::rational::Ratio::new(self.as_nanos(), rhs.as_nanos()))
+        }
+    }
+
+    fn mul_ratio(&self, numer: u64, denom: u64) -> Option<Duration> {
+        if denom == 0 {
+            return None;
+        }
+        let (numer, denom) = (numer as u128, denom as u128);
+        let nanos = self.as_nanos();
+        // `(q * d + r) * n / d == q * n + r * n / d`, where `r * n < u64::MAX^2` never overflows
+        let whole = (nanos / denom).checked_mul(numer)?;
+        let part = nanos % denom * numer / denom;
+        whole.checked_add(part).and_then(parse::nanos_to_duration)
+    }
+
+    fn lerp(&self, other: Duration, t: f64) -> Duration {
+        if t.is_nan() {
+            return *self;
+        }
+        let t = t.clamp(0.0, 1.0);
+        // `Duration::abs_diff` needs Rust 1.81
+        let span = other.max(*self) - other.min(*self);
+        // float rounding may overshoot the span slightly
+        let delta = span.checked_mul_f64(t).map_or(span, |d| d.min(span));
+        if other >= *self {
+            *self + delta
+        } else {
+            *self - delta
+        }
+    }
+
+    fn clamp_between(&self, a: Duration, b: Duration) -> Duration {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        (*self).clamp(lo, hi)
+    }
+}
@@ -0,0 +1,450 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::time::Duration;
+
+use super::{Clock, DurationExt};
+
+/// A source of randomness for jittered backoff schedules.
+///
+/// Implementations return values uniformly distributed in `[0, 1)`.
+/// Any `FnMut() -> f64` closure is a jitter source,
+/// which makes it easy to plug in a fixed sequence in tests.
+pub trait JitterSource {
+    /// Return the next value in `[0, 1)`.
+    fn next_unit(&mut self) -> f64;
+}
+
+impl<F: FnMut() -> f64> JitterSource for F {
+    fn next_unit(&mut self) -> f64 {
+        self()
+    }
+}
+
+/// A small deterministic pseudo-random generator (SplitMix64).
+///
+/// This is the default [`JitterSource`] of [`Backoff`].
+/// It is **not** suitable for cryptographic purposes.
+///
+/// The [`Default`] generator uses a fixed seed, so jittered schedules are reproducible.
+/// Use [`SplitMix64::from_entropy`] to keep separate clients from retrying in lockstep.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl Default for SplitMix64 {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl SplitMix64 {
+    /// Create a generator with a fixed seed, producing a reproducible sequence.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Create a generator seeded from the standard library's hasher randomness.
+    ///
+    /// The sequence differs between generators and between runs,
+    /// which spreads out the retries of many clients.
+    pub fn from_entropy() -> Self {
+        Self::new(RandomState::new().hash_one(0u8))
+    }
+
+    /// Return the next pseudo-random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl JitterSource for SplitMix64 {
+    fn next_unit(&mut self) -> f64 {
+        // the top 53 bits fill the mantissa of an `f64`
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// How the delays of a [`Backoff`] are randomized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Jitter {
+    /// Use the delays as they are.
+    #[default]
+    None,
+    /// Pick a random delay in `[0, delay]`.
+    Full,
+    /// Pick a random delay in `[delay / 2, delay]`.
+    Equal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Constant,
+    Linear(Duration),
+    Exponential(f64),
+    Fibonacci,
+    Decorrelated,
+}
+
+/// An iterator of delays between retries.
+///
+/// The schedule is selected with one of the constructors,
+/// and can be limited with [`Backoff::cap`], [`Backoff::max_retries`]
+/// (or [`Backoff::max_attempts`]) and [`Backoff::budget`].
+///
+/// Jittered schedules are reproducible by default, see [`SplitMix64`].
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{Backoff, DurationNumExt};
+///
+/// let delays: Vec<_> = Backoff::exponential(100.milliseconds(), 2.0)
+///     .cap(1.seconds())
+///     .max_retries(6)
+///     .collect();
+///
+/// assert_eq!(
+///     delays,
+///     [100, 200, 400, 800, 1000, 1000].map(|ms: u64| ms.milliseconds())
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Backoff<J = SplitMix64> {
+    kind: Kind,
+    initial: Duration,
+    jitter: Jitter,
+    source: J,
+    cap: Duration,
+    max_retries: Option<usize>,
+    budget: Option<Duration>,
+    retries: usize,
+    total: Duration,
+    previous: Duration,
+    current: Duration,
+}
+
+impl Backoff {
+    fn new(kind: Kind, initial: Duration) -> Self {
+        Self {
+            kind,
+            initial,
+            jitter: Jitter::None,
+            source: SplitMix64::default(),
+            cap: Duration::MAX,
+            max_retries: None,
+            budget: None,
+            retries: 0,
+            total: Duration::ZERO,
+            previous: Duration::ZERO,
+            current: initial,
+        }
+    }
+
+    /// Always wait the same `delay`.
+    pub fn constant(delay: Duration) -> Self {
+        Self::new(Kind::Constant, delay)
+    }
+
+    /// Wait `initial`, then increase the delay by `step` on every attempt.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{Backoff, DurationNumExt};
+    ///
+    /// let delays: Vec<_> = Backoff::linear(1.seconds(), 2.seconds()).take(3).collect();
+    /// assert_eq!(delays, [1.seconds(), 3.seconds(), 5.seconds()]);
+    /// ```
+    pub fn linear(initial: Duration, step: Duration) -> Self {
+        Self::new(Kind::Linear(step), initial)
+    }
+
+    /// Wait `initial`, then multiply the delay by `factor` on every attempt.
+    ///
+    /// Negative or `NaN` factors yield zero delays after the first attempt.
+    pub fn exponential(initial: Duration, factor: f64) -> Self {
+        Self::new(Kind::Exponential(factor), initial)
+    }
+
+    /// Wait `initial` times the Fibonacci numbers `1, 1, 2, 3, 5, ...`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{Backoff, DurationNumExt};
+    ///
+    /// let delays: Vec<_> = Backoff::fibonacci(1.seconds()).take(5).collect();
+    /// assert_eq!(delays, [1, 1, 2, 3, 5].map(|s: u64| s.seconds()));
+    /// ```
+    pub fn fibonacci(initial: Duration) -> Self {
+        Self::new(Kind::Fibonacci, initial)
+    }
+
+    /// The "decorrelated jitter" schedule:
+    /// each delay is picked at random from `[base, previous * 3]`.
+    ///
+    /// The schedule is inherently random, so [`Backoff::jitter`] has no effect.
+    /// It is recommended to set a [`Backoff::cap`].
+    pub fn decorrelated_jitter(base: Duration) -> Self {
+        Self::new(Kind::Decorrelated, base)
+    }
+}
+
+impl<J: JitterSource> Backoff<J> {
+    /// Limit every delay to at most `cap`.
+    ///
+    /// The cap is applied before the jitter.
+    pub fn cap(mut self, cap: Duration) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    /// Yield at most `retries` delays.
+    ///
+    /// Every delay is followed by one retry,
+    /// so [`retry`] calls the operation at most `retries + 1` times.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{retry, Backoff, DurationNumExt};
+    ///
+    /// let mut calls = 0;
+    /// let result: Result<(), _> = retry(Backoff::constant(1.milliseconds()).max_retries(2), || {
+    ///     calls += 1;
+    ///     Err(calls)
+    /// });
+    /// assert_eq!(result, Err(vec![1, 2, 3]));
+    /// ```
+    pub fn max_retries(mut self, retries: usize) -> Self {
+        self.max_retries = Some(retries);
+        self
+    }
+
+    /// Limit [`retry`] to at most `attempts` calls of the operation.
+    ///
+    /// This is the same as `max_retries(attempts - 1)`.
+    /// The operation is always called at least once, so `0` behaves like `1`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{retry, Backoff, DurationNumExt};
+    ///
+    /// let mut calls = 0;
+    /// let result: Result<(), _> = retry(Backoff::constant(1.milliseconds()).max_attempts(2), || {
+    ///     calls += 1;
+    ///     Err(calls)
+    /// });
+    /// assert_eq!(result, Err(vec![1, 2]));
+    /// ```
+    pub fn max_attempts(self, attempts: usize) -> Self {
+        self.max_retries(attempts.saturating_sub(1))
+    }
+
+    /// Stop before the total of all delays would exceed `budget`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{Backoff, DurationNumExt};
+    ///
+    /// let delays: Vec<_> = Backoff::constant(3.seconds()).budget(10.seconds()).collect();
+    /// assert_eq!(delays, [3.seconds(); 3]);
+    /// ```
+    pub fn budget(mut self, budget: Duration) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Randomize the delays.
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Replace the source of randomness, e.g. with another seed,
+    /// or with [`SplitMix64::from_entropy`] to spread out the retries of many clients.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{Backoff, DurationNumExt, Jitter, SplitMix64};
+    ///
+    /// let schedule = || {
+    ///     Backoff::constant(1.seconds())
+    ///         .jitter(Jitter::Full)
+    ///         .jitter_source(SplitMix64::new(42))
+    ///         .take(4)
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(schedule(), schedule());
+    /// assert!(schedule().iter().all(|d| *d <= 1.seconds()));
+    /// ```
+    pub fn jitter_source<S: JitterSource>(self, source: S) -> Backoff<S> {
+        Backoff {
+            kind: self.kind,
+            initial: self.initial,
+            jitter: self.jitter,
+            source,
+            cap: self.cap,
+            max_retries: self.max_retries,
+            budget: self.budget,
+            retries: self.retries,
+            total: self.total,
+            previous: self.previous,
+            current: self.current,
+        }
+    }
+
+    /// The total of all delays yielded so far.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// The number of delays yielded so far.
+    pub fn retries(&self) -> usize {
+        self.retries
+    }
+
+    fn unit(&mut self) -> f64 {
+        self.source.next_unit().clamp(0.0, 1.0)
+    }
+
+    /// The delay before the next retry, before capping and jitter.
+    fn advance(&mut self) -> Duration {
+        match self.kind {
+            Kind::Constant => self.initial,
+            Kind::Linear(step) => {
+                let delay = self.current;
+                self.current = self.current.saturating_add(step);
+                delay
+            }
+            Kind::Exponential(factor) => {
+                let delay = self.current;
+                self.current = self.current.saturating_mul_f64(factor);
+                delay
+            }
+            Kind::Fibonacci => {
+                let delay = self.current;
+                // `previous` starts at zero, so the sequence starts with `1, 1`
+                self.current = self.previous.saturating_add(self.current);
+                self.previous = delay;
+                delay
+            }
+            Kind::Decorrelated => {
+                let upper = self.current.saturating_mul(3).max(self.initial);
+                let t = self.unit();
+                let delay = self.initial.lerp(upper, t).min(self.cap);
+                self.current = delay;
+                delay
+            }
+        }
+    }
+}
+
+impl<J: JitterSource> Iterator for Backoff<J> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.max_retries.is_some_and(|max| self.retries >= max) {
+            return None;
+        }
+
+        let delay = self.advance().min(self.cap);
+        let delay = match (self.jitter, self.kind) {
+            (_, Kind::Decorrelated) | (Jitter::None, _) => delay,
+            (Jitter::Full, _) => Duration::ZERO.lerp(delay, self.unit()),
+            (Jitter::Equal, _) => (delay / 2).lerp(delay, self.unit()),
+        };
+
+        let total = self.total.checked_add(delay)?;
+        if self.budget.is_some_and(|budget| total > budget) {
+            // fuse the iterator, so a smaller jittered delay is not yielded later
+            self.max_retries = Some(self.retries);
+            return None;
+        }
+
+        self.retries += 1;
+        self.total = total;
+        Some(delay)
+    }
+}
+
+/// Run `op` until it succeeds, sleeping between attempts according to `schedule`.
+///
+/// `op` is called once, and once more after every delay of the schedule.
+/// If it never succeeds, all collected errors are returned in order.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{retry, Backoff, DurationNumExt};
+///
+/// let mut calls = 0;
+/// let result = retry(Backoff::constant(1.milliseconds()).max_retries(3), || {
+///     calls += 1;
+///     if calls < 3 { Err(calls) } else { Ok("done") }
+/// });
+/// assert_eq!(result, Ok("done"));
+///
+/// let result: Result<(), _> = retry(Backoff::constant(1.milliseconds()).max_retries(2), || Err("nope"));
+/// assert_eq!(result, Err(vec!["nope"; 3]));
+/// ```
+pub fn retry<T, E>(
+    schedule: impl IntoIterator<Item = Duration>,
+    op: impl FnMut() -> Result<T, E>,
+) -> Result<T, Vec<E>> {
+    retry_with(schedule, std::thread::sleep, op)
+}
+
+/// Like [`retry`], but sleeps on `clock` between attempts.
+///
+/// With a [`crate::time::ManualClock`], the schedule runs without actually sleeping.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{retry_on, Backoff, Clock, DurationNumExt, ManualClock};
+///
+/// let clock = ManualClock::new();
+/// let start = clock.now();
+/// let result: Result<(), _> = retry_on(&clock, Backoff::constant(1.minutes()).max_retries(2), || {
+///     Err(clock.elapsed(start))
+/// });
+/// assert_eq!(result, Err(vec![0.minutes(), 1.minutes(), 2.minutes()]));
+/// ```
+pub fn retry_on<T, E>(
+    clock: &impl Clock,
+    schedule: impl IntoIterator<Item = Duration>,
+    op: impl FnMut() -> Result<T, E>,
+) -> Result<T, Vec<E>> {
+    retry_with(schedule, |delay| clock.sleep(delay), op)
+}
+
+/// Like [`retry`], but waits between attempts with a custom `sleep` function.
+///
+/// This is useful for tests, or to wait on something other than the current thread.
+pub fn retry_with<T, E>(
+    schedule: impl IntoIterator<Item = Duration>,
+    mut sleep: impl FnMut(Duration),
+    mut op: impl FnMut() -> Result<T, E>,
+) -> Result<T, Vec<E>> {
+    let mut errors = Vec::new();
+    let mut schedule = schedule.into_iter();
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(e) => errors.push(e),
+        }
+        match schedule.next() {
+            Some(delay) => sleep(delay),
+            None => return Err(errors),
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant};
+
+/// An opaque point in time produced by a [`Clock`].
+///
+/// Like [`Instant`], timestamps are only meaningful relative to each other,
+/// and only when produced by the same kind of clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    /// The offset from the origin of the clock.
+    offset: Duration,
+}
+
+impl Timestamp {
+    /// The amount of time elapsed from `earlier` to `self`.
+    ///
+    /// Returns `None` if `earlier` is later than `self`.
+    pub fn checked_duration_since(&self, earlier: Timestamp) -> Option<Duration> {
+        self.offset.checked_sub(earlier.offset)
+    }
+
+    /// The amount of time elapsed from `earlier` to `self`, or zero if `earlier` is later.
+    pub fn saturating_duration_since(&self, earlier: Timestamp) -> Duration {
+        self.offset.saturating_sub(earlier.offset)
+    }
+
+    /// Returns `Some(t)` where `t` is `self + duration`, or `None` on overflow.
+    pub fn checked_add(&self, duration: Duration) -> Option<Timestamp> {
+        self.offset
+            .checked_add(duration)
+            .map(|offset| Timestamp { offset })
+    }
+
+    /// Returns `self + duration`, saturating at the latest representable timestamp.
+    pub fn saturating_add(&self, duration: Duration) -> Timestamp {
+        Timestamp {
+            offset: self.offset.saturating_add(duration),
+        }
+    }
+
+    /// Returns `Some(t)` where `t` is `self - duration`,
+    /// or `None` if the result would be before the origin of the clock.
+    pub fn checked_sub(&self, duration: Duration) -> Option<Timestamp> {
+        self.offset
+            .checked_sub(duration)
+            .map(|offset| Timestamp { offset })
+    }
+}
+
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// ## Panics
+    ///
+    /// This function panics if the result overflows.
+    fn add(self, rhs: Duration) -> Timestamp {
+        self.checked_add(rhs)
+            .expect("overflow when adding duration to timestamp")
+    }
+}
+
+impl AddAssign<Duration> for Timestamp {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// ## Panics
+    ///
+    /// This function panics if the result is before the origin of the clock.
+    fn sub(self, rhs: Duration) -> Timestamp {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting duration from timestamp")
+    }
+}
+
+impl SubAssign<Duration> for Timestamp {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub<Timestamp> for Timestamp {
+    type Output = Duration;
+
+    /// Like [`Timestamp::saturating_duration_since`].
+    fn sub(self, rhs: Timestamp) -> Duration {
+        self.saturating_duration_since(rhs)
+    }
+}
+
+/// A source of monotonic time.
+///
+/// Code that takes a clock instead of calling [`Instant::now`] directly
+/// can be tested deterministically with a [`ManualClock`].
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{Clock, DurationNumExt, ManualClock};
+///
+/// fn is_stale(clock: &impl Clock, since: rs_std_ext::time::Timestamp) -> bool {
+///     clock.elapsed(since) > 30.seconds()
+/// }
+///
+/// let clock = ManualClock::new();
+/// let fetched = clock.now();
+/// assert!(!is_stale(&clock, fetched));
+///
+/// clock.advance(31.seconds());
+/// assert!(is_stale(&clock, fetched));
+/// ```
+pub trait Clock {
+    /// The current time.
+    fn now(&self) -> Timestamp;
+
+    /// Wait for `duration` to pass.
+    fn sleep(&self, duration: Duration);
+
+    /// The time elapsed since `earlier`, or zero if `earlier` is in the future.
+    fn elapsed(&self, earlier: Timestamp) -> Duration {
+        self.now().saturating_duration_since(earlier)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Timestamp {
+        (**self).now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Box<C> {
+    fn now(&self) -> Timestamp {
+        (**self).now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Rc<C> {
+    fn now(&self) -> Timestamp {
+        (**self).now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> Timestamp {
+        (**self).now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration)
+    }
+}
+
+/// The real monotonic clock, backed by [`Instant`].
+///
+/// All `SystemClock`s share the same origin,
+/// so their timestamps can be compared with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Timestamp {
+        static ORIGIN: OnceLock<Instant> = OnceLock::new();
+        let origin = *ORIGIN.get_or_init(Instant::now);
+        Timestamp {
+            offset: origin.elapsed(),
+        }
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration)
+    }
+}
+
+/// A clock that only moves when told to, for tests.
+///
+/// Clones share the same time, so one handle can be passed to the code under test
+/// while another one advances it. Sleeping advances the clock immediately.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{Clock, DurationNumExt, ManualClock};
+///
+/// let clock = ManualClock::new();
+/// let handle = clock.clone();
+/// let start = clock.now();
+///
+/// handle.advance(5.seconds());
+/// clock.sleep(1.seconds());
+/// assert_eq!(clock.now() - start, 6.seconds());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<Timestamp>>,
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ManualClock {
+    /// Create a clock at its origin.
+    pub fn new() -> Self {
+        let origin = Timestamp {
+            offset: Duration::ZERO,
+        };
+        Self {
+            now: Arc::new(Mutex::new(origin)),
+        }
+    }
+
+    /// Move the clock forward by `duration`.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the time overflows.
+    pub fn advance(&self, duration: Duration) {
+        *self.lock() += duration;
+    }
+
+    /// Move the clock forward to `timestamp`.
+    ///
+    /// The clock is monotonic, so this does nothing if `timestamp` is in the past.
+    pub fn advance_to(&self, timestamp: Timestamp) {
+        let mut now = self.lock();
+        *now = (*now).max(timestamp);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Timestamp> {
+        // a panic while holding the lock cannot leave the timestamp in an invalid state
+        self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Timestamp {
+        *self.lock()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.advance(duration)
+    }
+}
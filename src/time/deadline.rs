@@ -0,0 +1,237 @@
+use std::time::{Duration, Instant};
+
+use super::{Clock, DurationExt, InstantExt, TimePoint, Timestamp};
+
+/// A point in time by which some work has to be finished.
+///
+/// A deadline is a thin wrapper around [`Instant`] that offers budget-related operations,
+/// such as splitting the remaining time between sub-calls.
+///
+/// A `Deadline<Timestamp>` is measured on a [`Clock`] instead,
+/// see the `*_on` methods, e.g. [`Deadline::after_on`].
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{Deadline, DurationNumExt};
+/// use std::time::Instant;
+///
+/// let deadline = Deadline::from(Instant::now() + 5.seconds());
+/// assert!(!deadline.is_expired());
+///
+/// // give 30% of the remaining budget to a sub-call
+/// let sub = deadline.sub_deadline(0.3);
+/// assert!(sub < deadline);
+/// assert!(sub.remaining() <= 1500.milliseconds());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Deadline<T = Instant> {
+    instant: T,
+}
+
+impl<T: TimePoint> Deadline<T> {
+    /// Create a deadline at the given instant.
+    pub fn at(instant: T) -> Self {
+        Self { instant }
+    }
+
+    /// The instant of the deadline.
+    pub fn instant(&self) -> T {
+        self.instant
+    }
+
+    /// The time left until the deadline, measured from `now`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{Deadline, DurationNumExt};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let now = Instant::now();
+    /// let deadline = Deadline::at(now + 5.seconds());
+    /// assert_eq!(deadline.remaining_at(now), 5.seconds());
+    /// assert_eq!(deadline.remaining_at(now + 10.seconds()), Duration::ZERO);
+    /// ```
+    pub fn remaining_at(&self, now: T) -> Duration {
+        self.instant.saturating_duration_since(now)
+    }
+
+    /// Returns `true` if the deadline has been reached at `now`.
+    pub fn is_expired_at(&self, now: T) -> bool {
+        now >= self.instant
+    }
+
+    /// The earlier of two deadlines.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{Deadline, DurationNumExt};
+    /// use std::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let a = Deadline::at(now + 1.seconds());
+    /// let b = Deadline::at(now + 2.seconds());
+    /// assert_eq!(a.min(b), a);
+    /// assert_eq!(b.min(a), a);
+    /// ```
+    pub fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+
+    /// Create a deadline that receives `fraction` of the budget remaining at `now`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{Deadline, DurationNumExt};
+    /// use std::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let deadline = Deadline::at(now + 10.seconds());
+    /// assert_eq!(deadline.sub_deadline_at(now, 0.3), Deadline::at(now + 3.seconds()));
+    /// assert_eq!(deadline.sub_deadline_at(now, 2.0), deadline);
+    /// ```
+    pub fn sub_deadline_at(&self, now: T, fraction: f64) -> Self {
+        let budget = Duration::ZERO.lerp(self.remaining_at(now), fraction);
+        (*self).min(self.budget_from(now, budget))
+    }
+
+    /// Create a deadline that keeps `reserve` of the budget remaining at `now` for the caller.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{Deadline, DurationNumExt};
+    /// use std::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let deadline = Deadline::at(now + 10.seconds());
+    /// assert_eq!(deadline.reserve_at(now, 2.seconds()), Deadline::at(now + 8.seconds()));
+    /// assert_eq!(deadline.reserve_at(now, 20.seconds()), Deadline::at(now));
+    /// ```
+    pub fn reserve_at(&self, now: T, reserve: Duration) -> Self {
+        let budget = self.remaining_at(now).saturating_sub(reserve);
+        self.budget_from(now, budget)
+    }
+
+    /// The deadline `budget` after `now`.
+    ///
+    /// The budget never exceeds the time remaining at `now`,
+    /// so the result is at most `self` and cannot overflow.
+    fn budget_from(&self, now: T, budget: Duration) -> Self {
+        Self::at(now.checked_add(budget).unwrap_or(self.instant))
+    }
+}
+
+impl Deadline {
+    /// Create a deadline `timeout` from now.
+    ///
+    /// The deadline saturates at the latest representable instant.
+    pub fn after(timeout: Duration) -> Self {
+        Self::at(Instant::now().saturating_add(timeout))
+    }
+
+    /// The time left until the deadline, or `Duration::ZERO` if it has expired.
+    pub fn remaining(&self) -> Duration {
+        self.remaining_at(Instant::now())
+    }
+
+    /// Returns `true` if the deadline has been reached.
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(Instant::now())
+    }
+
+    /// Block the current thread until the deadline has been reached.
+    ///
+    /// Returns immediately if the deadline has already expired.
+    pub fn sleep_until(&self) {
+        // `thread::sleep` may wake up early on some platforms
+        while !self.is_expired() {
+            std::thread::sleep(self.remaining());
+        }
+    }
+
+    /// Create a deadline that receives `fraction` of the remaining budget.
+    ///
+    /// `fraction` is clamped to `[0, 1]`, so the sub-deadline never exceeds `self`.
+    pub fn sub_deadline(&self, fraction: f64) -> Deadline {
+        self.sub_deadline_at(Instant::now(), fraction)
+    }
+
+    /// Create a deadline that keeps `reserve` of the budget for the caller,
+    /// e.g. to handle the result of a sub-call.
+    ///
+    /// The result saturates at `now`, so it is already expired
+    /// if less than `reserve` remains.
+    pub fn reserve(&self, reserve: Duration) -> Deadline {
+        self.reserve_at(Instant::now(), reserve)
+    }
+}
+
+impl Deadline<Timestamp> {
+    /// Create a deadline `timeout` from now on `clock`.
+    ///
+    /// The deadline saturates at the latest representable timestamp.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{Clock, Deadline, DurationNumExt, ManualClock};
+    ///
+    /// let clock = ManualClock::new();
+    /// let deadline = Deadline::after_on(&clock, 10.seconds());
+    ///
+    /// clock.advance(4.seconds());
+    /// assert_eq!(deadline.remaining_on(&clock), 6.seconds());
+    /// assert_eq!(deadline.reserve_on(&clock, 2.seconds()).remaining_on(&clock), 4.seconds());
+    ///
+    /// deadline.sleep_until_on(&clock);
+    /// assert!(deadline.is_expired_on(&clock));
+    /// ```
+    pub fn after_on(clock: &impl Clock, timeout: Duration) -> Self {
+        Self::at(clock.now().saturating_add(timeout))
+    }
+
+    /// The time left until the deadline on `clock`, or `Duration::ZERO` if it has expired.
+    pub fn remaining_on(&self, clock: &impl Clock) -> Duration {
+        self.remaining_at(clock.now())
+    }
+
+    /// Returns `true` if the deadline has been reached on `clock`.
+    pub fn is_expired_on(&self, clock: &impl Clock) -> bool {
+        self.is_expired_at(clock.now())
+    }
+
+    /// Sleep on `clock` until the deadline has been reached.
+    ///
+    /// Returns immediately if the deadline has already expired.
+    pub fn sleep_until_on(&self, clock: &impl Clock) {
+        while !self.is_expired_on(clock) {
+            clock.sleep(self.remaining_on(clock));
+        }
+    }
+
+    /// Create a deadline that receives `fraction` of the budget remaining on `clock`.
+    pub fn sub_deadline_on(&self, clock: &impl Clock, fraction: f64) -> Self {
+        self.sub_deadline_at(clock.now(), fraction)
+    }
+
+    /// Create a deadline that keeps `reserve` of the budget remaining on `clock` for the caller.
+    pub fn reserve_on(&self, clock: &impl Clock, reserve: Duration) -> Self {
+        self.reserve_at(clock.now(), reserve)
+    }
+}
+
+impl<T: TimePoint> From<T> for Deadline<T> {
+    fn from(instant: T) -> Self {
+        Self::at(instant)
+    }
+}
+
+impl From<Deadline> for Instant {
+    fn from(deadline: Deadline) -> Self {
+        deadline.instant
+    }
+}
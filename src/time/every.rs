@@ -0,0 +1,236 @@
+use std::time::{Duration, Instant};
+
+use super::parse::nanos_to_duration;
+use super::{Clock, TimePoint, Timestamp};
+
+/// What a [`Ticker`] does when ticks were missed, because it was polled too late.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MissedTicks {
+    /// Fire once, and continue with the next tick in the future, keeping the alignment.
+    #[default]
+    Skip,
+    /// Fire every missed tick, as fast as the ticker is polled, until it has caught up.
+    Burst,
+    /// Fire once, and continue a full period after the late tick, losing the alignment.
+    Delay,
+}
+
+/// A periodic schedule, e.g. every 15 minutes.
+///
+/// `Every` only describes the schedule,
+/// [`Every::starting`] creates a [`Ticker`] that yields the firings.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{DurationNumExt, Every};
+/// use std::time::UNIX_EPOCH;
+///
+/// // on the quarter hour, wall-clock aligned
+/// let start = UNIX_EPOCH + 10.hours() + 7.minutes();
+/// let ticker = Every::new(15.minutes()).aligned_to(0.seconds()).starting(start);
+///
+/// let upcoming: Vec<_> = ticker.upcoming().take(3).collect();
+/// assert_eq!(
+///     upcoming,
+///     [15, 30, 45].map(|m: u64| UNIX_EPOCH + 10.hours() + m.minutes())
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Every {
+    period: Duration,
+    offset: Option<Duration>,
+    missed: MissedTicks,
+}
+
+impl Every {
+    /// Fire every `period`.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if `period` is zero.
+    pub fn new(period: Duration) -> Self {
+        assert!(
+            !period.is_zero(),
+            "the period of a schedule must not be zero"
+        );
+        Self {
+            period,
+            offset: None,
+            missed: MissedTicks::default(),
+        }
+    }
+
+    /// Fire at `offset` past every multiple of the period.
+    ///
+    /// For `SystemTime`s, the multiples are counted from the unix epoch (in UTC),
+    /// also for times before it, and for `Duration`s from zero, see [`TimePoint::since_origin`].
+    /// Other types have no origin, so the multiples are counted from the start of the ticker.
+    ///
+    /// Without an alignment, the first tick is at the start of the ticker.
+    pub fn aligned_to(mut self, offset: Duration) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set what happens when ticks were missed. The default is [`MissedTicks::Skip`].
+    pub fn on_missed(mut self, missed: MissedTicks) -> Self {
+        self.missed = missed;
+        self
+    }
+
+    /// The period of the schedule.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Start the schedule at `start`, i.e. the first tick is the first aligned time at or after it.
+    pub fn starting<T: TimePoint>(&self, start: T) -> Ticker<T> {
+        let next = match self.offset {
+            None => Some(start),
+            Some(offset) => {
+                let period = self.period.as_nanos();
+                // `rem_euclid` keeps the phase of a start before the origin positive
+                let phase = start.since_origin().map_or(0, |since| {
+                    since.as_nanos().rem_euclid(period as i128) as u128
+                });
+                let delay = (offset.as_nanos() % period + period - phase) % period;
+                // the delay is less than the period, so it fits in a `Duration`
+                start.checked_add(from_nanos(delay))
+            }
+        };
+        Ticker { every: *self, next }
+    }
+}
+
+/// Convert nanoseconds to a `Duration`, saturating at `Duration::MAX`.
+fn from_nanos(nanos: u128) -> Duration {
+    nanos_to_duration(nanos).unwrap_or(Duration::MAX)
+}
+
+/// The firings of an [`Every`] schedule.
+///
+/// The ticker is driven by polling it with the current time,
+/// so it works with any clock, including a [`ManualClock`](super::ManualClock).
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{Clock, DurationNumExt, Every, ManualClock};
+///
+/// let clock = ManualClock::new();
+/// let start = clock.now();
+/// let mut ticker = Every::new(10.seconds()).starting(start);
+///
+/// assert_eq!(ticker.poll(clock.now()), Some(start));
+/// assert_eq!(ticker.poll(clock.now()), None);
+/// assert_eq!(ticker.remaining(clock.now()), 10.seconds());
+///
+/// // two ticks were missed, the next one is kept aligned
+/// clock.advance(25.seconds());
+/// assert_eq!(ticker.poll(clock.now()), Some(start + 10.seconds()));
+/// assert_eq!(ticker.next_tick(), Some(start + 30.seconds()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ticker<T> {
+    every: Every,
+    /// The next tick, or `None` if it is not representable.
+    next: Option<T>,
+}
+
+impl<T: TimePoint> Ticker<T> {
+    /// The time of the next tick, or `None` if it is not representable.
+    pub fn next_tick(&self) -> Option<T> {
+        self.next
+    }
+
+    /// The time left until the next tick, measured from `now`.
+    ///
+    /// Returns `Duration::MAX` if there are no more ticks.
+    pub fn remaining(&self, now: T) -> Duration {
+        self.next
+            .map_or(Duration::MAX, |next| next.saturating_duration_since(now))
+    }
+
+    /// Fire the next tick if it is due at `now`, and return its scheduled time.
+    ///
+    /// Missed ticks are handled according to [`Every::on_missed`].
+    pub fn poll(&mut self, now: T) -> Option<T> {
+        let tick = self.next.filter(|next| *next <= now)?;
+        let period = self.every.period;
+        self.next = match self.every.missed {
+            MissedTicks::Burst => tick.checked_add(period),
+            MissedTicks::Delay => now.checked_add(period),
+            MissedTicks::Skip => {
+                let late = now.saturating_duration_since(tick).as_nanos();
+                let skipped = late / period.as_nanos() + 1;
+                period
+                    .as_nanos()
+                    .checked_mul(skipped)
+                    .and_then(|offset| tick.checked_add(from_nanos(offset)))
+            }
+        };
+        Some(tick)
+    }
+
+    /// The upcoming ticks, assuming none is missed.
+    pub fn upcoming(&self) -> Upcoming<T> {
+        Upcoming {
+            next: self.next,
+            period: self.every.period,
+        }
+    }
+}
+
+impl Ticker<Instant> {
+    /// Block the current thread until the next tick, and fire it.
+    ///
+    /// Returns `None` if there are no more ticks.
+    pub fn wait(&mut self) -> Option<Instant> {
+        let next = self.next?;
+        std::thread::sleep(next.saturating_duration_since(Instant::now()));
+        self.poll(Instant::now().max(next))
+    }
+}
+
+impl Ticker<Timestamp> {
+    /// Sleep on `clock` until the next tick, and fire it.
+    ///
+    /// Returns `None` if there are no more ticks.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{Clock, DurationNumExt, Every, ManualClock};
+    ///
+    /// let clock = ManualClock::new();
+    /// let start = clock.now();
+    /// let mut ticker = Every::new(1.minutes()).starting(start);
+    ///
+    /// ticker.wait_on(&clock);
+    /// ticker.wait_on(&clock);
+    /// assert_eq!(clock.now(), start + 1.minutes());
+    /// ```
+    pub fn wait_on(&mut self, clock: &impl Clock) -> Option<Timestamp> {
+        let next = self.next?;
+        clock.sleep(next.saturating_duration_since(clock.now()));
+        self.poll(clock.now().max(next))
+    }
+}
+
+/// An iterator over the upcoming ticks of a [`Ticker`], created by [`Ticker::upcoming`].
+#[derive(Debug, Clone)]
+pub struct Upcoming<T> {
+    next: Option<T>,
+    period: Duration,
+}
+
+impl<T: TimePoint> Iterator for Upcoming<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tick = self.next?;
+        self.next = tick.checked_add(self.period);
+        Some(tick)
+    }
+}
@@ -0,0 +1,360 @@
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use super::TimeUnit;
+
+/// Maximum number of fractional digits that can be rendered for units up to a second.
+///
+/// Nine digits of a second is exactly one nanosecond,
+/// the resolution of a [`std::time::Duration`].
+/// Larger units allow more digits, see [`max_precision`].
+const MAX_PRECISION: u32 = 9;
+
+/// The naming style of the units in a human-readable duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum UnitStyle {
+    /// Abbreviated unit names without separators, e.g. `1d 2h 3m 4.5s`.
+    #[default]
+    Short,
+    /// Full unit names, e.g. `1 day 2 hours 3 minutes 4.5 seconds`.
+    Long,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum FormatStyle {
+    Human,
+    Compact,
+    Iso8601,
+}
+
+/// A formatter for [`std::time::Duration`], created by the `display_*` methods
+/// of [`crate::time::DurationExt`].
+///
+/// The output can be tuned with the builder methods below.
+/// The precision can also be overridden with the standard format syntax,
+/// e.g. `format!("{:.2}", d.display_human())`,
+/// and the width, fill and alignment are honored as for strings.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{DurationExt, TimeUnit, UnitStyle};
+/// use std::time::Duration;
+///
+/// let d = Duration::new(93_784, 500_000_000);
+///
+/// assert_eq!(d.display_human().to_string(), "1d 2h 3m 4.5s");
+/// assert_eq!(
+///     d.display_human()
+///         .largest_unit(TimeUnit::Hour)
+///         .unit_style(UnitStyle::Long)
+///         .to_string(),
+///     "26 hours 3 minutes 4.5 seconds"
+/// );
+/// assert_eq!(format!("{:.0}", d.display_human()), "1d 2h 3m 5s");
+/// assert_eq!(format!("{:>8}", Duration::from_secs(90).display_human()), "  1m 30s");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DurationDisplay {
+    duration: Duration,
+    style: FormatStyle,
+    precision: Option<u32>,
+    largest: TimeUnit,
+    smallest: TimeUnit,
+    unit_style: UnitStyle,
+}
+
+impl DurationDisplay {
+    pub(crate) fn human(duration: Duration) -> Self {
+        Self {
+            duration,
+            style: FormatStyle::Human,
+            precision: None,
+            largest: TimeUnit::Day,
+            smallest: TimeUnit::Second,
+            unit_style: UnitStyle::Short,
+        }
+    }
+
+    pub(crate) fn compact(duration: Duration) -> Self {
+        Self {
+            duration,
+            style: FormatStyle::Compact,
+            precision: Some(3),
+            largest: TimeUnit::Hour,
+            smallest: TimeUnit::Second,
+            unit_style: UnitStyle::Short,
+        }
+    }
+
+    pub(crate) fn iso8601(duration: Duration) -> Self {
+        Self {
+            duration,
+            style: FormatStyle::Iso8601,
+            precision: None,
+            largest: TimeUnit::Day,
+            smallest: TimeUnit::Second,
+            unit_style: UnitStyle::Short,
+        }
+    }
+
+    /// Set the number of fractional digits of the smallest unit.
+    ///
+    /// `None` renders as many digits as needed to show a single nanosecond
+    /// of the smallest unit, rounded (half-up) and with trailing zeros removed.
+    /// This is exact for units up to a second; e.g. with weeks as the smallest unit,
+    /// 15 digits are rendered, and one second is `0.000001653439153w`.
+    ///
+    /// `Some(n)` rounds (half-up) to exactly `n` digits,
+    /// but never renders more digits than `None` does.
+    pub fn precision(mut self, precision: Option<u32>) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Set the largest unit to be rendered.
+    ///
+    /// Values exceeding the largest unit are not carried any further,
+    /// e.g. `26h` instead of `1d 2h`.
+    ///
+    /// The compact and ISO 8601 formats only support units from seconds to days,
+    /// and will clamp the value to that range.
+    pub fn largest_unit(mut self, unit: TimeUnit) -> Self {
+        self.largest = unit;
+        self
+    }
+
+    /// Set the smallest unit to be rendered.
+    ///
+    /// Anything less than the smallest unit is rendered as its fraction.
+    ///
+    /// The compact format always ends with seconds and ignores this option,
+    /// while the ISO 8601 format does not support units smaller than a second.
+    pub fn smallest_unit(mut self, unit: TimeUnit) -> Self {
+        self.smallest = unit;
+        self
+    }
+
+    /// Set the naming style of the units.
+    ///
+    /// This only affects the human-readable format.
+    pub fn unit_style(mut self, style: UnitStyle) -> Self {
+        self.unit_style = style;
+        self
+    }
+
+    fn fmt_human(&self, f: &mut impl fmt::Write, precision: Option<u32>) -> fmt::Result {
+        let largest = self.largest.max(self.smallest);
+        let split = Split::new(self.duration, largest, self.smallest, precision);
+
+        let mut written = false;
+        for unit in units_between(self.smallest, largest) {
+            let value = split.parts[unit.index()];
+            let last = unit == self.smallest;
+            // zero components are skipped, unless the fraction or nothing else remains
+            if value == 0 && !(last && (split.fraction != 0 || !written)) {
+                continue;
+            }
+            let fraction = if last { split.fraction() } else { None };
+
+            if written {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", value)?;
+            if let Some(fraction) = &fraction {
+                write!(f, ".{}", fraction)?;
+            }
+            match self.unit_style {
+                UnitStyle::Short => f.write_str(unit.short_name())?,
+                UnitStyle::Long => {
+                    write!(f, " {}", unit.long_name())?;
+                    if value != 1 || fraction.is_some() {
+                        f.write_str("s")?;
+                    }
+                }
+            }
+            written = true;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_compact(&self, f: &mut impl fmt::Write, precision: Option<u32>) -> fmt::Result {
+        let largest = self.largest.clamp(TimeUnit::Second, TimeUnit::Day);
+        let split = Split::new(self.duration, largest, TimeUnit::Second, precision);
+
+        let mut written = false;
+        for unit in units_between(TimeUnit::Second, largest) {
+            if written {
+                f.write_str(":")?;
+            }
+            write!(f, "{:02}", split.parts[unit.index()])?;
+            written = true;
+        }
+        if let Some(fraction) = split.fraction() {
+            write!(f, ".{}", fraction)?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_iso8601(&self, f: &mut impl fmt::Write, precision: Option<u32>) -> fmt::Result {
+        let largest = self.largest.clamp(TimeUnit::Second, TimeUnit::Day);
+        let smallest = self.smallest.clamp(TimeUnit::Second, largest);
+        let split = Split::new(self.duration, largest, smallest, precision);
+
+        f.write_str("P")?;
+        let mut written = false;
+        let mut in_time = false;
+        for unit in units_between(smallest, largest) {
+            let value = split.parts[unit.index()];
+            let last = unit == smallest;
+            if value == 0 && !(last && (split.fraction != 0 || !written)) {
+                continue;
+            }
+            let fraction = if last { split.fraction() } else { None };
+
+            if unit < TimeUnit::Day && !in_time {
+                f.write_str("T")?;
+                in_time = true;
+            }
+            write!(f, "{}", value)?;
+            if let Some(fraction) = &fraction {
+                write!(f, ".{}", fraction)?;
+            }
+            f.write_str(match unit {
+                TimeUnit::Day => "D",
+                TimeUnit::Hour => "H",
+                TimeUnit::Minute => "M",
+                _ => "S",
+            })?;
+            written = true;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for DurationDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f
+            .precision()
+            .map(|p| p.min(u32::MAX as usize) as u32)
+            .or(self.precision);
+        let mut rendered = String::new();
+        match self.style {
+            FormatStyle::Human => self.fmt_human(&mut rendered, precision)?,
+            FormatStyle::Compact => self.fmt_compact(&mut rendered, precision)?,
+            FormatStyle::Iso8601 => self.fmt_iso8601(&mut rendered, precision)?,
+        }
+        pad(f, &rendered)
+    }
+}
+
+/// Write `s` to `f`, honoring the width, fill and alignment of the formatter.
+///
+/// Unlike [`fmt::Formatter::pad`], this never truncates `s`,
+/// since the duration formats use the precision for their fractional digits.
+pub(crate) fn pad(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let len = s.chars().count();
+    let padding = match f.width() {
+        Some(width) if width > len => width - len,
+        _ => return f.write_str(s),
+    };
+    // strings are left-aligned by default
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Iterate over the units in `smallest..=largest`, from the largest to the smallest.
+fn units_between(smallest: TimeUnit, largest: TimeUnit) -> impl Iterator<Item = TimeUnit> {
+    TimeUnit::ALL
+        .into_iter()
+        .rev()
+        .filter(move |u| *u >= smallest && *u <= largest)
+}
+
+/// The number of fractional digits needed to show one nanosecond of `unit`,
+/// but at least [`MAX_PRECISION`].
+fn max_precision(unit: TimeUnit) -> u32 {
+    let mut digits = 0;
+    while 10u128.pow(digits) < unit.as_nanos() {
+        digits += 1;
+    }
+    digits.max(MAX_PRECISION)
+}
+
+/// A duration broken into per-unit components.
+struct Split {
+    /// The value of each unit, indexed by [`TimeUnit::index`].
+    parts: [u128; TimeUnit::ALL.len()],
+    /// The fraction of the smallest unit, scaled by `10^digits`.
+    fraction: u128,
+    digits: u32,
+    trim: bool,
+}
+
+impl Split {
+    fn new(
+        duration: Duration,
+        largest: TimeUnit,
+        smallest: TimeUnit,
+        precision: Option<u32>,
+    ) -> Self {
+        let (digits, trim) = match precision {
+            Some(p) => (p.min(max_precision(smallest)), false),
+            None => (max_precision(smallest), true),
+        };
+        let scale = 10u128.pow(digits);
+        let unit = smallest.as_nanos();
+
+        // round half-up to `digits` fractional digits of the smallest unit,
+        // cannot overflow: the remainder is below a week, and `scale` at most `10^15`
+        let nanos = duration.as_nanos();
+        let mut rest = nanos / unit;
+        let mut fraction = (nanos % unit * scale * 2 + unit) / (unit * 2);
+        if fraction == scale {
+            rest += 1;
+            fraction = 0;
+        }
+
+        let mut parts = [0; TimeUnit::ALL.len()];
+        for u in units_between(smallest, largest) {
+            let ratio = u.as_nanos() / unit;
+            parts[u.index()] = rest / ratio;
+            rest %= ratio;
+        }
+
+        Self {
+            parts,
+            fraction,
+            digits,
+            trim,
+        }
+    }
+
+    /// The rendered fractional digits, or `None` if there is nothing to render.
+    fn fraction(&self) -> Option<String> {
+        if self.digits == 0 || (self.trim && self.fraction == 0) {
+            return None;
+        }
+        let mut s = format!("{:0width$}", self.fraction, width = self.digits as usize);
+        if self.trim {
+            s.truncate(s.trim_end_matches('0').len());
+        }
+        Some(s)
+    }
+}
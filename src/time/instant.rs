@@ -0,0 +1,113 @@
+use std::time::{Duration, Instant};
+
+/// Extension methods for [`std::time::Instant`].
+pub trait InstantExt {
+    /// The amount of time elapsed from `earlier` to `self`.
+    ///
+    /// Returns `None` if `earlier` is later than `self`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::InstantExt;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let start = Instant::now();
+    /// let end = start + Duration::from_secs(1);
+    /// assert_eq!(end.elapsed_since_checked(start), Some(Duration::from_secs(1)));
+    /// assert_eq!(start.elapsed_since_checked(end), None);
+    /// ```
+    fn elapsed_since_checked(&self, earlier: Instant) -> Option<Duration>;
+
+    /// Add a duration, saturating at the latest representable instant.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::InstantExt;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let now = Instant::now();
+    /// assert!(now.saturating_add(Duration::MAX) > now);
+    /// ```
+    fn saturating_add(&self, duration: Duration) -> Instant;
+
+    /// Subtract a duration, saturating at the earliest representable instant.
+    fn saturating_sub(&self, duration: Duration) -> Instant;
+
+    /// The time left until `self` is reached, treating it as a deadline.
+    ///
+    /// Returns `Duration::ZERO` if the deadline has passed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::InstantExt;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// assert!(deadline.deadline_remaining() > Duration::from_secs(50));
+    /// assert_eq!(Instant::now().deadline_remaining(), Duration::ZERO);
+    /// ```
+    fn deadline_remaining(&self) -> Duration;
+
+    /// Returns `true` if the current time is at or after `self`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::InstantExt;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// assert!(Instant::now().has_passed());
+    /// assert!(!(Instant::now() + Duration::from_secs(60)).has_passed());
+    /// ```
+    fn has_passed(&self) -> bool;
+}
+
+impl InstantExt for Instant {
+    fn elapsed_since_checked(&self, earlier: Instant) -> Option<Duration> {
+        self.checked_duration_since(earlier)
+    }
+
+    fn saturating_add(&self, duration: Duration) -> Instant {
+        saturate(*self, duration, Instant::checked_add)
+    }
+
+    fn saturating_sub(&self, duration: Duration) -> Instant {
+        saturate(*self, duration, Instant::checked_sub)
+    }
+
+    fn deadline_remaining(&self) -> Duration {
+        self.saturating_duration_since(Instant::now())
+    }
+
+    fn has_passed(&self) -> bool {
+        Instant::now() >= *self
+    }
+}
+
+/// Move `instant` by `duration` with `step`, getting as close as possible
+/// if the full duration is not representable.
+///
+/// The platform range of `Instant` is unknown,
+/// so the limit is approached by repeatedly halving the step.
+fn saturate(
+    mut instant: Instant,
+    duration: Duration,
+    step: impl Fn(&Instant, Duration) -> Option<Instant>,
+) -> Instant {
+    let mut remaining = duration;
+    let mut stride = duration;
+    while !remaining.is_zero() && !stride.is_zero() {
+        stride = stride.min(remaining);
+        match step(&instant, stride) {
+            Some(next) => {
+                instant = next;
+                remaining -= stride;
+            }
+            None => stride /= 2,
+        }
+    }
+    instant
+}
@@ -0,0 +1,241 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use super::{SignedDuration, SystemTimeExt, Timestamp};
+
+/// A point on a time line, that can be the bound of an [`Interval`].
+///
+/// This is implemented for `Duration` (as an offset from some origin),
+/// `Instant`, `SystemTime` and [`Timestamp`].
+pub trait TimePoint: Copy + Ord {
+    /// Returns `self + duration`, or `None` on overflow.
+    fn checked_add(&self, duration: Duration) -> Option<Self>;
+
+    /// The amount of time from `earlier` to `self`, or zero if `earlier` is later.
+    fn saturating_duration_since(&self, earlier: Self) -> Duration;
+
+    /// The signed offset from a well-known origin, if the type has one.
+    ///
+    /// This is used to align schedules to e.g. whole minutes.
+    /// `Duration` is measured from zero and `SystemTime` from the unix epoch,
+    /// where times before the epoch are negative. The other types have no origin.
+    fn since_origin(&self) -> Option<SignedDuration> {
+        None
+    }
+}
+
+impl TimePoint for Duration {
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        Duration::checked_add(*self, duration)
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.saturating_sub(earlier)
+    }
+
+    fn since_origin(&self) -> Option<SignedDuration> {
+        Some(SignedDuration::positive(*self))
+    }
+}
+
+impl TimePoint for Instant {
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        Instant::checked_add(self, duration)
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        Instant::saturating_duration_since(self, earlier)
+    }
+}
+
+impl TimePoint for SystemTime {
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        SystemTime::checked_add(self, duration)
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.duration_since(earlier).unwrap_or_default()
+    }
+
+    fn since_origin(&self) -> Option<SignedDuration> {
+        Some(self.unix_offset())
+    }
+}
+
+impl TimePoint for Timestamp {
+    fn checked_add(&self, duration: Duration) -> Option<Self> {
+        Timestamp::checked_add(self, duration)
+    }
+
+    fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        Timestamp::saturating_duration_since(self, earlier)
+    }
+}
+
+/// A half-open interval of time `[start, end)`.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{DurationNumExt, Interval};
+///
+/// let morning = Interval::new(8.hours(), 12.hours()).unwrap();
+/// let meeting = Interval::new(11.hours(), 13.hours()).unwrap();
+///
+/// assert!(morning.overlaps(&meeting));
+/// assert_eq!(morning.intersection(&meeting), Interval::new(11.hours(), 12.hours()));
+/// assert_eq!(morning.union(&meeting), Interval::new(8.hours(), 13.hours()));
+/// assert!(morning.contains(&8.hours()));
+/// assert!(!morning.contains(&12.hours()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: TimePoint> Interval<T> {
+    /// Create the interval `[start, end)`.
+    ///
+    /// Returns `None` if `end` is before `start`.
+    pub fn new(start: T, end: T) -> Option<Interval<T>> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    /// Create the interval of `length` beginning at `start`.
+    ///
+    /// Returns `None` if the end is not representable.
+    pub fn starting_at(start: T, length: Duration) -> Option<Interval<T>> {
+        Some(Interval {
+            start,
+            end: start.checked_add(length)?,
+        })
+    }
+
+    /// The inclusive start.
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// The exclusive end.
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// The length of the interval.
+    pub fn length(&self) -> Duration {
+        self.end.saturating_duration_since(self.start)
+    }
+
+    /// Returns `true` if the interval contains no point, i.e. `start == end`.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns `true` if `point` lies within the interval.
+    pub fn contains(&self, point: &T) -> bool {
+        self.start <= *point && *point < self.end
+    }
+
+    /// Returns `true` if `other` lies completely within `self`.
+    ///
+    /// An empty interval is contained in `self` if its position is.
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Returns `true` if the intervals share at least one point.
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// The points shared by both intervals, or `None` if they do not overlap.
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        self.overlaps(other).then(|| Interval {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+
+    /// The interval covering both intervals,
+    /// or `None` if they are neither overlapping nor adjacent.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationNumExt, Interval};
+    ///
+    /// let a = Interval::new(1.seconds(), 2.seconds()).unwrap();
+    /// let b = Interval::new(2.seconds(), 3.seconds()).unwrap();
+    /// let c = Interval::new(4.seconds(), 5.seconds()).unwrap();
+    ///
+    /// assert_eq!(a.union(&b), Interval::new(1.seconds(), 3.seconds()));
+    /// assert_eq!(a.union(&c), None);
+    /// ```
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        (self.start <= other.end && other.start <= self.end).then(|| Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// Split the interval into consecutive intervals of `step`.
+    ///
+    /// The last interval is shorter if the length is not a multiple of `step`.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if `step` is zero.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationNumExt, Interval};
+    ///
+    /// let slots: Vec<_> = Interval::new(0.minutes(), 50.minutes())
+    ///     .unwrap()
+    ///     .steps(20.minutes())
+    ///     .map(|slot| (slot.start(), slot.end()))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     slots,
+    ///     [
+    ///         (0.minutes(), 20.minutes()),
+    ///         (20.minutes(), 40.minutes()),
+    ///         (40.minutes(), 50.minutes()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn steps(&self, step: Duration) -> Steps<T> {
+        assert!(!step.is_zero(), "the step of an interval must not be zero");
+        Steps {
+            next: self.start,
+            end: self.end,
+            step,
+        }
+    }
+}
+
+/// An iterator over consecutive parts of an [`Interval`], created by [`Interval::steps`].
+#[derive(Debug, Clone)]
+pub struct Steps<T> {
+    next: T,
+    end: T,
+    step: Duration,
+}
+
+impl<T: TimePoint> Iterator for Steps<T> {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+        let start = self.next;
+        let end = start
+            .checked_add(self.step)
+            .map_or(self.end, |end| end.min(self.end));
+        self.next = end;
+        Some(Interval { start, end })
+    }
+}
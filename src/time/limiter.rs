@@ -0,0 +1,306 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use super::parse::nanos_to_duration;
+use super::{Clock, Rate, SystemClock, Timestamp};
+
+/// The time needed to gain `missing` scaled units at `count` units per nanosecond,
+/// rounded up to the nanosecond.
+fn wait_for(missing: u128, count: u64) -> Duration {
+    nanos_to_duration(missing.div_ceil(count as u128)).unwrap_or(Duration::MAX)
+}
+
+/// A token bucket: tokens are refilled at a [`Rate`] up to a maximum burst,
+/// and every permit consumes tokens.
+///
+/// The bucket starts full. Token accounting is exact, there is no rounding drift.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{DurationNumExt, ManualClock, RateNumExt, TokenBucket};
+///
+/// let clock = ManualClock::new();
+/// let mut bucket = TokenBucket::new(10.per(1.seconds()), 2).with_clock(clock.clone());
+///
+/// assert_eq!(bucket.try_acquire(2), Ok(()));
+/// // one token is refilled every 100ms
+/// assert_eq!(bucket.try_acquire(1), Err(100.milliseconds()));
+///
+/// clock.advance(100.milliseconds());
+/// assert_eq!(bucket.try_acquire(1), Ok(()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TokenBucket<C = SystemClock> {
+    rate: Rate,
+    burst: u64,
+    clock: C,
+    /// Tokens scaled by the period in nanoseconds, so a nanosecond refills `rate.count()` units.
+    ///
+    /// The scaled values saturate, so extreme rates and bursts cannot overflow.
+    tokens: u128,
+    updated: Option<Timestamp>,
+}
+
+impl TokenBucket {
+    /// Create a full bucket holding at most `burst` tokens, refilled at `rate`.
+    pub fn new(rate: Rate, burst: u64) -> Self {
+        Self {
+            rate,
+            burst,
+            clock: SystemClock,
+            tokens: (burst as u128).saturating_mul(rate.period().as_nanos()),
+            updated: None,
+        }
+    }
+}
+
+impl<C: Clock> TokenBucket<C> {
+    /// Replace the clock, e.g. with a [`ManualClock`](super::ManualClock) for tests.
+    pub fn with_clock<C2: Clock>(self, clock: C2) -> TokenBucket<C2> {
+        TokenBucket {
+            rate: self.rate,
+            burst: self.burst,
+            clock,
+            tokens: self.tokens,
+            updated: None,
+        }
+    }
+
+    /// The refill rate.
+    pub fn rate(&self) -> Rate {
+        self.rate
+    }
+
+    /// The maximum number of tokens.
+    pub fn burst(&self) -> u64 {
+        self.burst
+    }
+
+    /// The number of whole tokens currently available.
+    pub fn available(&mut self) -> u64 {
+        self.refill();
+        (self.tokens / self.rate.period().as_nanos()) as u64
+    }
+
+    /// Take `tokens` tokens if they are available.
+    ///
+    /// Otherwise, returns how long to wait until they are.
+    /// If `tokens` exceeds the burst, it never succeeds and `Duration::MAX` is returned.
+    pub fn try_acquire(&mut self, tokens: u64) -> Result<(), Duration> {
+        if tokens > self.burst {
+            return Err(Duration::MAX);
+        }
+        self.refill();
+        let needed = (tokens as u128).saturating_mul(self.rate.period().as_nanos());
+        match self.tokens.checked_sub(needed) {
+            Some(left) => {
+                self.tokens = left;
+                Ok(())
+            }
+            None => Err(wait_for(needed - self.tokens, self.rate.count())),
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = self.clock.now();
+        let elapsed = self.updated.map_or(Duration::ZERO, |t| now - t);
+        let capacity = (self.burst as u128).saturating_mul(self.rate.period().as_nanos());
+        let gained = elapsed.as_nanos().saturating_mul(self.rate.count() as u128);
+        self.tokens = self.tokens.saturating_add(gained).min(capacity);
+        self.updated = Some(now);
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Strategy {
+    FixedWindow {
+        start: Option<Timestamp>,
+        used: u64,
+    },
+    SlidingWindow(VecDeque<Timestamp>),
+    Gcra {
+        burst: u64,
+        origin: Option<Timestamp>,
+        /// The theoretical arrival time, in nanoseconds since `origin` scaled by the count.
+        tat: u128,
+    },
+}
+
+/// A rate limiter that grants permits at a [`Rate`], using one of several algorithms.
+///
+/// - [`RateLimiter::fixed_window`] allows `count` permits in consecutive windows of `period`.
+///   Up to twice the rate may pass around a window boundary.
+/// - [`RateLimiter::sliding_window`] allows `count` permits in any span of `period`.
+///   It remembers the time of the last `count` permits, so its memory grows with the count.
+/// - [`RateLimiter::gcra`] spaces permits evenly, allowing bursts of a given size
+///   (the generic cell rate algorithm).
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{DurationNumExt, ManualClock, RateLimiter, RateNumExt};
+///
+/// let clock = ManualClock::new();
+/// let mut limiter = RateLimiter::sliding_window(2.per(1.seconds())).with_clock(clock.clone());
+///
+/// assert_eq!(limiter.try_acquire(), Ok(()));
+/// clock.advance(400.milliseconds());
+/// assert_eq!(limiter.try_acquire(), Ok(()));
+/// assert_eq!(limiter.try_acquire(), Err(600.milliseconds()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter<C = SystemClock> {
+    rate: Rate,
+    clock: C,
+    strategy: Strategy,
+}
+
+impl RateLimiter {
+    fn new(rate: Rate, strategy: Strategy) -> Self {
+        Self {
+            rate,
+            clock: SystemClock,
+            strategy,
+        }
+    }
+
+    /// A fixed window limiter.
+    ///
+    /// The first window starts with the first permit.
+    pub fn fixed_window(rate: Rate) -> Self {
+        Self::new(
+            rate,
+            Strategy::FixedWindow {
+                start: None,
+                used: 0,
+            },
+        )
+    }
+
+    /// A sliding window (log) limiter.
+    ///
+    /// The limiter is exact, but keeps a 16-byte timestamp for each of the last
+    /// `count` permits, so its memory grows with the count of the rate:
+    /// at full load, `100_000.per(1.seconds())` holds about 1.6MB of timestamps.
+    /// Expired timestamps are dropped from the front, so a permit is still amortized `O(1)`.
+    /// For large counts, consider [`RateLimiter::gcra`], which needs constant memory.
+    pub fn sliding_window(rate: Rate) -> Self {
+        Self::new(rate, Strategy::SlidingWindow(VecDeque::new()))
+    }
+
+    /// A GCRA limiter, allowing up to `burst` permits at once.
+    ///
+    /// A `burst` of zero is treated as one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationNumExt, ManualClock, RateLimiter, RateNumExt};
+    ///
+    /// let clock = ManualClock::new();
+    /// let mut limiter = RateLimiter::gcra(10.per(1.seconds()), 1).with_clock(clock.clone());
+    ///
+    /// assert_eq!(limiter.try_acquire(), Ok(()));
+    /// assert_eq!(limiter.try_acquire(), Err(100.milliseconds()));
+    /// clock.advance(30.milliseconds());
+    /// assert_eq!(limiter.try_acquire(), Err(70.milliseconds()));
+    /// ```
+    pub fn gcra(rate: Rate, burst: u64) -> Self {
+        Self::new(
+            rate,
+            Strategy::Gcra {
+                burst: burst.max(1),
+                origin: None,
+                tat: 0,
+            },
+        )
+    }
+}
+
+impl<C: Clock> RateLimiter<C> {
+    /// Replace the clock, e.g. with a [`ManualClock`](super::ManualClock) for tests.
+    pub fn with_clock<C2: Clock>(self, clock: C2) -> RateLimiter<C2> {
+        let mut limiter = RateLimiter {
+            rate: self.rate,
+            clock,
+            strategy: self.strategy,
+        };
+        limiter.reset();
+        limiter
+    }
+
+    /// The rate of the limiter.
+    pub fn rate(&self) -> Rate {
+        self.rate
+    }
+
+    /// Forget all previous permits.
+    pub fn reset(&mut self) {
+        match &mut self.strategy {
+            Strategy::FixedWindow { start, used } => {
+                *start = None;
+                *used = 0;
+            }
+            Strategy::SlidingWindow(log) => log.clear(),
+            Strategy::Gcra { origin, tat, .. } => {
+                *origin = None;
+                *tat = 0;
+            }
+        }
+    }
+
+    /// Take a permit if one is available.
+    ///
+    /// Otherwise, returns how long to wait until one is.
+    pub fn try_acquire(&mut self) -> Result<(), Duration> {
+        let now = self.clock.now();
+        let (count, period) = (self.rate.count(), self.rate.period());
+
+        match &mut self.strategy {
+            Strategy::FixedWindow { start, used } => {
+                let mut window = *start.get_or_insert(now);
+                let elapsed = now - window;
+                if elapsed >= period {
+                    // skip to the window containing `now`
+                    let skipped = elapsed.as_nanos() / period.as_nanos() * period.as_nanos();
+                    window += nanos_to_duration(skipped).unwrap_or(elapsed);
+                    *start = Some(window);
+                    *used = 0;
+                }
+                if *used < count {
+                    *used += 1;
+                    Ok(())
+                } else {
+                    let end = window.checked_add(period);
+                    Err(end.map_or(Duration::MAX, |end| end - now))
+                }
+            }
+            Strategy::SlidingWindow(log) => {
+                while log.front().is_some_and(|t| now - *t >= period) {
+                    log.pop_front();
+                }
+                if (log.len() as u64) < count {
+                    log.push_back(now);
+                    Ok(())
+                } else {
+                    let oldest = log.front().copied().unwrap_or(now);
+                    Err(period - (now - oldest))
+                }
+            }
+            Strategy::Gcra { burst, origin, tat } => {
+                let origin = *origin.get_or_insert(now);
+                let now = (now - origin).as_nanos().saturating_mul(count as u128);
+                let interval = period.as_nanos();
+                let next = (*tat).max(now).saturating_add(interval);
+                let allow_at = next.saturating_sub((*burst as u128).saturating_mul(interval));
+                if now >= allow_at {
+                    *tat = next;
+                    Ok(())
+                } else {
+                    Err(wait_for(allow_at - now, count))
+                }
+            }
+        }
+    }
+}
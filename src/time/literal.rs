@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use super::parse::unit_from_bytes;
+use super::NANOS_PER_SEC;
+
+/// Create a [`std::time::Duration`] from a compound literal, at compile time.
+///
+/// The literal is a sequence of numbers, each followed by a unit,
+/// e.g. `duration!(1h 30m 5s 250ms)`.
+/// The units are the ones accepted by [`parse_duration`](crate::time::extfn::parse_duration),
+/// numbers may contain `_` separators and a fractional part, e.g. `1.5h`.
+/// As with `parse_duration`, each unit may appear only once,
+/// and a decimal point must be followed by at least one digit.
+///
+/// The value is always evaluated at compile time,
+/// so the macro can be used in `const` items,
+/// and an invalid or overflowing literal is a compile error.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::duration;
+/// use std::time::Duration;
+///
+/// const TIMEOUT: Duration = duration!(1h 30m 5s 250ms);
+/// assert_eq!(TIMEOUT, Duration::from_millis(5_405_250));
+///
+/// assert_eq!(duration!(1.5s), Duration::from_millis(1_500));
+/// assert_eq!(duration!(2 weeks), Duration::from_secs(1_209_600));
+/// ```
+///
+/// Overflowing literals fail to compile:
+///
+/// ```rust,compile_fail
+/// use rs_std_ext::duration;
+///
+/// let _ = duration!(1_000_000_000_000_000w);
+/// ```
+///
+/// So do repeated units:
+///
+/// ```rust,compile_fail
+/// use rs_std_ext::duration;
+///
+/// let _ = duration!(1s 1s);
+/// ```
+///
+/// And decimal points without fractional digits:
+///
+/// ```rust,compile_fail
+/// use rs_std_ext::duration;
+///
+/// let _ = duration!(1.s);
+/// ```
+#[macro_export]
+macro_rules! duration {
+    ($($tt:tt)+) => {{
+        const DURATION: ::std::time::Duration =
+            $crate::time::__duration_literal(::std::stringify!($($tt)+));
+        DURATION
+    }};
+}
+
+/// Fractional digits beyond this are ignored,
+/// as they contribute less than a nanosecond even for weeks.
+const MAX_FRACTION_DIGITS: u32 = 18;
+
+/// Evaluate the literal of [`duration!`](crate::duration).
+///
+/// Panics, i.e. fails the compilation, if the literal is invalid or overflows.
+#[doc(hidden)]
+pub const fn __duration_literal(literal: &str) -> Duration {
+    let bytes = literal.as_bytes();
+    let mut pos = 0;
+    let mut total: u128 = 0;
+    let mut empty = true;
+    // the units seen so far, as a bitmask of `TimeUnit::index`
+    let mut seen: u32 = 0;
+
+    while pos < bytes.len() {
+        if bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+            continue;
+        }
+
+        // the integer part
+        let start = pos;
+        let mut int: u128 = 0;
+        while pos < bytes.len() && (bytes[pos].is_ascii_digit() || bytes[pos] == b'_') {
+            if bytes[pos] != b'_' {
+                int = match int.checked_mul(10) {
+                    Some(v) => checked_add(v, (bytes[pos] - b'0') as u128),
+                    None => panic!("duration literal overflows"),
+                };
+            }
+            pos += 1;
+        }
+        if pos == start {
+            panic!("expected a number in duration literal");
+        }
+
+        // the fractional part
+        let mut fraction: u128 = 0;
+        let mut scale: u128 = 1;
+        if pos < bytes.len() && bytes[pos] == b'.' {
+            pos += 1;
+            let mut digits = 0;
+            let mut any_digit = false;
+            while pos < bytes.len() && (bytes[pos].is_ascii_digit() || bytes[pos] == b'_') {
+                if bytes[pos] != b'_' {
+                    any_digit = true;
+                    if digits < MAX_FRACTION_DIGITS {
+                        fraction = fraction * 10 + (bytes[pos] - b'0') as u128;
+                        scale *= 10;
+                        digits += 1;
+                    }
+                }
+                pos += 1;
+            }
+            if !any_digit {
+                panic!("expected a digit after the decimal point in duration literal");
+            }
+        }
+
+        while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+
+        // the unit, which ends at whitespace or at the next number
+        let start = pos;
+        while pos < bytes.len() && !bytes[pos].is_ascii_whitespace() && !bytes[pos].is_ascii_digit()
+        {
+            pos += 1;
+        }
+        let (_, rest) = bytes.split_at(start);
+        let (name, _) = rest.split_at(pos - start);
+        let unit = match unit_from_bytes(name) {
+            Some(unit) => unit,
+            None if name.is_empty() => panic!("missing unit in duration literal"),
+            None => panic!("unknown unit in duration literal"),
+        };
+        let bit = 1 << unit.index();
+        if seen & bit != 0 {
+            panic!("repeated unit in duration literal");
+        }
+        seen |= bit;
+        let unit = unit.as_nanos();
+
+        let value = match int.checked_mul(unit) {
+            Some(v) => checked_add(v, fraction * unit / scale),
+            None => panic!("duration literal overflows"),
+        };
+        total = checked_add(total, value);
+        empty = false;
+    }
+
+    if empty {
+        panic!("empty duration literal");
+    }
+
+    let secs = total / NANOS_PER_SEC as u128;
+    if secs > u64::MAX as u128 {
+        panic!("duration literal overflows");
+    }
+    Duration::new(secs as u64, (total % NANOS_PER_SEC as u128) as u32)
+}
+
+const fn checked_add(a: u128, b: u128) -> u128 {
+    match a.checked_add(b) {
+        Some(v) => v,
+        None => panic!("duration literal overflows"),
+    }
+}
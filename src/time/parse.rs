@@ -0,0 +1,412 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::{TimeUnit, NANOS_PER_SEC};
+
+/// Fractional digits beyond this are ignored, as they are far below
+/// the nanosecond resolution of any unit.
+const MAX_FRACTION_DIGITS: usize = 20;
+
+/// The reason why a duration string failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParseDurationErrorKind {
+    /// The input is empty or contains only whitespace.
+    Empty,
+    /// A number was expected but not found, or is malformed.
+    InvalidNumber,
+    /// A number is not followed by a unit.
+    MissingUnit,
+    /// The unit is not recognized.
+    UnknownUnit(String),
+    /// An unexpected character, or a unit or field in the wrong place.
+    InvalidFormat,
+    /// A clock field exceeds its range, e.g. `00:61:00`.
+    FieldOutOfRange,
+    /// The value cannot be represented by a `Duration`.
+    Overflow,
+}
+
+/// An error returned when parsing a duration string.
+///
+/// The error records the byte offset in the input where the problem was found.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseDurationError {
+    kind: ParseDurationErrorKind,
+    position: usize,
+}
+
+impl ParseDurationError {
+    pub(crate) fn new(kind: ParseDurationErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    pub(crate) fn offset_by(mut self, offset: usize) -> Self {
+        self.position += offset;
+        self
+    }
+
+    /// The reason of the error.
+    pub fn kind(&self) -> &ParseDurationErrorKind {
+        &self.kind
+    }
+
+    /// The byte offset in the input where the error occurred.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseDurationErrorKind::Empty => f.write_str("empty duration string")?,
+            ParseDurationErrorKind::InvalidNumber => f.write_str("invalid number")?,
+            ParseDurationErrorKind::MissingUnit => f.write_str("missing unit")?,
+            ParseDurationErrorKind::UnknownUnit(unit) => write!(f, "unknown unit `{}`", unit)?,
+            ParseDurationErrorKind::InvalidFormat => f.write_str("invalid duration format")?,
+            ParseDurationErrorKind::FieldOutOfRange => f.write_str("clock field out of range")?,
+            ParseDurationErrorKind::Overflow => f.write_str("duration overflow")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl Error for ParseDurationError {}
+
+impl FromStr for TimeUnit {
+    type Err = ParseDurationError;
+
+    /// Parse a unit name, e.g. `ms`, `sec` or `hours`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        unit_from_name(s).ok_or_else(|| {
+            ParseDurationError::new(ParseDurationErrorKind::UnknownUnit(s.to_string()), 0)
+        })
+    }
+}
+
+fn unit_from_name(name: &str) -> Option<TimeUnit> {
+    unit_from_bytes(name.as_bytes())
+}
+
+/// Look up a unit by one of its names.
+///
+/// This works on bytes so that it can be used in `const` contexts.
+pub(crate) const fn unit_from_bytes(name: &[u8]) -> Option<TimeUnit> {
+    Some(match name {
+        b"ns" | b"nsec" | b"nsecs" | b"nanosecond" | b"nanoseconds" => TimeUnit::Nanosecond,
+        // `µs` is spelled with either the micro sign or the greek letter mu
+        b"us"
+        | [0xc2, 0xb5, b's']
+        | [0xce, 0xbc, b's']
+        | b"usec"
+        | b"usecs"
+        | b"microsecond"
+        | b"microseconds" => TimeUnit::Microsecond,
+        b"ms" | b"msec" | b"msecs" | b"millisecond" | b"milliseconds" => TimeUnit::Millisecond,
+        b"s" | b"sec" | b"secs" | b"second" | b"seconds" => TimeUnit::Second,
+        b"m" | b"min" | b"mins" | b"minute" | b"minutes" => TimeUnit::Minute,
+        b"h" | b"hr" | b"hrs" | b"hour" | b"hours" => TimeUnit::Hour,
+        b"d" | b"day" | b"days" => TimeUnit::Day,
+        b"w" | b"week" | b"weeks" => TimeUnit::Week,
+        _ => return None,
+    })
+}
+
+/// Parse a duration from a string.
+///
+/// See [`crate::time::extfn::parse_duration`] for the supported formats.
+pub(crate) fn parse_duration(s: &str) -> Result<Duration, ParseDurationError> {
+    let start = s.len() - s.trim_start().len();
+    let input = s.trim();
+    if input.is_empty() {
+        return Err(ParseDurationError::new(ParseDurationErrorKind::Empty, 0));
+    }
+
+    let mut parser = Parser {
+        src: input.as_bytes(),
+        pos: 0,
+        offset: start,
+    };
+    let nanos = if input.starts_with('P') {
+        parser.iso8601()?
+    } else if input.contains(':') {
+        parser.clock()?
+    } else {
+        parser.compound()?
+    };
+
+    nanos_to_duration(nanos)
+        .ok_or_else(|| ParseDurationError::new(ParseDurationErrorKind::Overflow, start))
+}
+
+pub(crate) fn nanos_to_duration(nanos: u128) -> Option<Duration> {
+    let secs = (nanos / NANOS_PER_SEC as u128).try_into().ok()?;
+    Some(Duration::new(secs, (nanos % NANOS_PER_SEC as u128) as u32))
+}
+
+/// A decimal number as written in the input.
+struct Number<'a> {
+    int: &'a str,
+    fraction: &'a str,
+    position: usize,
+}
+
+impl Number<'_> {
+    /// The value of the number in nanoseconds, where the number is measured in `unit`.
+    fn to_nanos(&self, unit: TimeUnit) -> Result<u128, ParseDurationError> {
+        let overflow = || ParseDurationError::new(ParseDurationErrorKind::Overflow, self.position);
+        let unit = unit.as_nanos();
+
+        let int: u128 = if self.int.is_empty() {
+            0
+        } else {
+            self.int.parse().map_err(|_| overflow())?
+        };
+        let int = int.checked_mul(unit).ok_or_else(overflow)?;
+
+        let digits = &self.fraction[..self.fraction.len().min(MAX_FRACTION_DIGITS)];
+        let fraction = if digits.is_empty() {
+            0
+        } else {
+            // cannot overflow: the largest unit is a week,
+            // and 10^20 * (nanoseconds per week) < 10^35 < u128::MAX
+            digits.parse::<u128>().unwrap() * unit / 10u128.pow(digits.len() as u32)
+        };
+
+        int.checked_add(fraction).ok_or_else(overflow)
+    }
+}
+
+struct Parser<'a> {
+    src: &'a [u8],
+    pos: usize,
+    /// The offset of `src` in the original input.
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, kind: ParseDurationErrorKind, pos: usize) -> ParseDurationError {
+        ParseDurationError::new(kind, self.offset + pos)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.get(self.pos).copied()
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos >= self.src.len()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn take_while(&mut self, f: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(&f) {
+            self.pos += 1;
+        }
+        // only ever split at ASCII bytes or at the end of a multibyte run
+        std::str::from_utf8(&self.src[start..self.pos]).unwrap()
+    }
+
+    fn number(&mut self) -> Result<Number<'a>, ParseDurationError> {
+        let position = self.offset + self.pos;
+        let int = self.take_while(|c| c.is_ascii_digit());
+        let dot = self.eat(b'.');
+        let fraction = if dot {
+            self.take_while(|c| c.is_ascii_digit())
+        } else {
+            ""
+        };
+        // a dot must be followed by digits, e.g. `1.` is rejected
+        if (int.is_empty() && !dot) || (dot && fraction.is_empty()) {
+            return Err(ParseDurationError::new(
+                ParseDurationErrorKind::InvalidNumber,
+                position,
+            ));
+        }
+        Ok(Number {
+            int,
+            fraction,
+            position,
+        })
+    }
+
+    /// `1h 30m 15.25s`, `1 day 2 hours`, `1h30m`
+    ///
+    /// Every unit may appear at most once, in any order.
+    fn compound(&mut self) -> Result<u128, ParseDurationError> {
+        let mut total: u128 = 0;
+        let mut seen = Vec::new();
+        while !self.is_done() {
+            let number = self.number()?;
+            self.skip_whitespace();
+
+            let unit_start = self.pos;
+            let name = self.take_while(|c| c.is_ascii_alphabetic() || !c.is_ascii());
+            if name.is_empty() {
+                return Err(match self.peek() {
+                    None => self.error(ParseDurationErrorKind::MissingUnit, unit_start),
+                    Some(_) => self.error(ParseDurationErrorKind::InvalidFormat, unit_start),
+                });
+            }
+            let unit = unit_from_name(name).ok_or_else(|| {
+                self.error(
+                    ParseDurationErrorKind::UnknownUnit(name.to_string()),
+                    unit_start,
+                )
+            })?;
+            if seen.contains(&unit) {
+                return Err(self.error(ParseDurationErrorKind::InvalidFormat, unit_start));
+            }
+            seen.push(unit);
+
+            total = total
+                .checked_add(number.to_nanos(unit)?)
+                .ok_or_else(|| self.error(ParseDurationErrorKind::Overflow, unit_start))?;
+            self.skip_whitespace();
+        }
+        Ok(total)
+    }
+
+    /// `01:02:03.5`, `02:03`, `1:02:03:04`
+    ///
+    /// Every field but the first must have exactly two digits.
+    fn clock(&mut self) -> Result<u128, ParseDurationError> {
+        let mut fields = Vec::new();
+        loop {
+            let number = self.number()?;
+            let last = !self.eat(b':');
+            if !last && !number.fraction.is_empty() {
+                return Err(self.error(ParseDurationErrorKind::InvalidFormat, self.pos - 1));
+            }
+            fields.push(number);
+            if last {
+                break;
+            }
+        }
+        if !self.is_done() {
+            return Err(self.error(ParseDurationErrorKind::InvalidFormat, self.pos));
+        }
+
+        let units: &[TimeUnit] = match fields.len() {
+            2 => &[TimeUnit::Minute, TimeUnit::Second],
+            3 => &[TimeUnit::Hour, TimeUnit::Minute, TimeUnit::Second],
+            4 => &[
+                TimeUnit::Day,
+                TimeUnit::Hour,
+                TimeUnit::Minute,
+                TimeUnit::Second,
+            ],
+            _ => return Err(self.error(ParseDurationErrorKind::InvalidFormat, 0)),
+        };
+
+        let mut total: u128 = 0;
+        for (i, (number, unit)) in fields.iter().zip(units).enumerate() {
+            if number.int.is_empty() {
+                return Err(ParseDurationError::new(
+                    ParseDurationErrorKind::InvalidNumber,
+                    number.position,
+                ));
+            }
+            if i != 0 {
+                if number.int.len() < 2 {
+                    return Err(ParseDurationError::new(
+                        ParseDurationErrorKind::InvalidNumber,
+                        number.position,
+                    ));
+                }
+                let limit = match unit {
+                    TimeUnit::Hour => 24,
+                    _ => 60,
+                };
+                if number.int.len() > 2 || number.int.parse::<u32>().unwrap() >= limit {
+                    return Err(ParseDurationError::new(
+                        ParseDurationErrorKind::FieldOutOfRange,
+                        number.position,
+                    ));
+                }
+            }
+            total = total.checked_add(number.to_nanos(*unit)?).ok_or_else(|| {
+                ParseDurationError::new(ParseDurationErrorKind::Overflow, number.position)
+            })?;
+        }
+        Ok(total)
+    }
+
+    /// `P1DT2H3M4.5S`, `PT90M`
+    ///
+    /// Only the last component may have a fraction.
+    fn iso8601(&mut self) -> Result<u128, ParseDurationError> {
+        self.eat(b'P');
+        let mut total: u128 = 0;
+        let mut in_time = false;
+        let mut previous: Option<TimeUnit> = None;
+        let mut fractional = false;
+        let mut empty = true;
+
+        while !self.is_done() {
+            if self.eat(b'T') {
+                if in_time || self.is_done() {
+                    return Err(self.error(ParseDurationErrorKind::InvalidFormat, self.pos - 1));
+                }
+                in_time = true;
+                continue;
+            }
+
+            if fractional {
+                return Err(self.error(ParseDurationErrorKind::InvalidFormat, self.pos));
+            }
+            let number = self.number()?;
+            let designator = self.pos;
+            let unit = match (self.peek(), in_time) {
+                (Some(b'W'), false) => TimeUnit::Week,
+                (Some(b'D'), false) => TimeUnit::Day,
+                (Some(b'H'), true) => TimeUnit::Hour,
+                (Some(b'M'), true) => TimeUnit::Minute,
+                (Some(b'S'), true) => TimeUnit::Second,
+                (None, _) => {
+                    return Err(self.error(ParseDurationErrorKind::MissingUnit, designator));
+                }
+                (Some(c), _) if c.is_ascii_alphabetic() => {
+                    let name = (c as char).to_string();
+                    return Err(self.error(ParseDurationErrorKind::UnknownUnit(name), designator));
+                }
+                (Some(_), _) => {
+                    return Err(self.error(ParseDurationErrorKind::InvalidFormat, designator));
+                }
+            };
+            self.pos += 1;
+
+            if previous.is_some_and(|p| p <= unit) {
+                return Err(self.error(ParseDurationErrorKind::InvalidFormat, designator));
+            }
+            previous = Some(unit);
+            fractional = !number.fraction.is_empty();
+            empty = false;
+
+            total = total
+                .checked_add(number.to_nanos(unit)?)
+                .ok_or_else(|| self.error(ParseDurationErrorKind::Overflow, designator))?;
+        }
+
+        if empty {
+            return Err(self.error(ParseDurationErrorKind::InvalidFormat, self.pos));
+        }
+        Ok(total)
+    }
+}
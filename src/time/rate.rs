@@ -0,0 +1,155 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::parse::{
+    nanos_to_duration, parse_duration, unit_from_bytes, ParseDurationError, ParseDurationErrorKind,
+};
+use super::{DurationExt, TimeUnit};
+
+/// A number of events per period of time, e.g. 100 requests per second.
+///
+/// Rates are usually written with [`RateNumExt::per`], or parsed from strings like `"100/s"`.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{DurationNumExt, Rate, RateNumExt};
+///
+/// let rate = 100.per(1.seconds());
+/// assert_eq!(rate.interval(), 10.milliseconds());
+/// assert_eq!("100/s".parse::<Rate>(), Ok(rate));
+/// assert_eq!(rate.to_string(), "100/s");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rate {
+    count: u64,
+    period: Duration,
+}
+
+impl Rate {
+    /// Create a rate of `count` events per `period`.
+    ///
+    /// Returns `None` if `count` or `period` is zero.
+    pub fn new(count: u64, period: Duration) -> Option<Rate> {
+        (count > 0 && !period.is_zero()).then_some(Rate { count, period })
+    }
+
+    /// The number of events per period.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The period.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// The average time between two events, rounded up to the nanosecond.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationNumExt, RateNumExt};
+    ///
+    /// assert_eq!(3.per(1.seconds()).interval(), 333_333_334.nanoseconds());
+    /// ```
+    pub fn interval(&self) -> Duration {
+        // the interval is at most the period, so it is always representable
+        nanos_to_duration(self.period.as_nanos().div_ceil(self.count as u128))
+            .unwrap_or(self.period)
+    }
+
+    /// The number of events per second.
+    pub fn per_second(&self) -> f64 {
+        self.count as f64 / self.period.as_secs_f64()
+    }
+}
+
+impl fmt::Display for Rate {
+    /// Formats as `count/period`, e.g. `100/s` or `5/1m 30s`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = TimeUnit::ALL
+            .into_iter()
+            .rev()
+            .find(|unit| unit.as_duration() == self.period);
+        match unit {
+            Some(unit) => write!(f, "{}/{}", self.count, unit.short_name()),
+            None => write!(f, "{}/{}", self.count, self.period.display_human()),
+        }
+    }
+}
+
+impl FromStr for Rate {
+    type Err = ParseDurationError;
+
+    /// Parse a rate like `100/s`, `5 / 1m 30s` or `10/minute`.
+    ///
+    /// The period is either a single unit, or any duration accepted by
+    /// [`parse_duration`](super::extfn::parse_duration).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(slash) = s.find('/') else {
+            let kind = ParseDurationErrorKind::InvalidFormat;
+            return Err(ParseDurationError::new(kind, s.len()));
+        };
+        let (count, period) = (&s[..slash], &s[slash + 1..]);
+
+        let start = count.len() - count.trim_start().len();
+        let count: u64 = match count.trim().parse() {
+            Ok(count) if count > 0 => count,
+            _ => {
+                let kind = ParseDurationErrorKind::InvalidNumber;
+                return Err(ParseDurationError::new(kind, start));
+            }
+        };
+
+        let offset = slash + 1;
+        let period = match unit_from_bytes(period.trim().as_bytes()) {
+            Some(unit) => unit.as_duration(),
+            None => parse_duration(period).map_err(|e| e.offset_by(offset))?,
+        };
+        Rate::new(count, period).ok_or_else(|| {
+            let start = s.len() - s[offset..].trim_start().len();
+            ParseDurationError::new(ParseDurationErrorKind::InvalidFormat, start)
+        })
+    }
+}
+
+/// Create a [`Rate`] using the postfix syntax, e.g. `100.per(1.seconds())`.
+pub trait RateNumExt {
+    /// Create a rate of `self` events per `period`.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if `self` is not positive or does not fit in a `u64`,
+    /// or if `period` is zero.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationNumExt, RateNumExt};
+    ///
+    /// let rate = 5.per(1.minutes());
+    /// assert_eq!(rate.count(), 5);
+    /// assert_eq!(rate.period(), 60.seconds());
+    /// ```
+    fn per(&self, period: Duration) -> Rate;
+}
+
+macro_rules! __impl_rate_num {
+    ($($ty:ty),*) => {
+        $(
+            impl RateNumExt for $ty {
+                #[inline]
+                fn per(&self, period: Duration) -> Rate {
+                    u64::try_from(*self)
+                        .ok()
+                        .and_then(|count| Rate::new(count, period))
+                        .expect("a rate needs a positive count and a non-zero period")
+                }
+            }
+        )*
+    };
+}
+
+__impl_rate_num!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
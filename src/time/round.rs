@@ -0,0 +1,145 @@
+use std::cmp::Ordering;
+use std::time::Duration;
+
+use super::{
+    parse, TimeUnit, NANOS_PER_MICRO, NANOS_PER_MILLI, SECS_PER_DAY, SECS_PER_HOUR,
+    SECS_PER_MINUTE, SECS_PER_WEEK,
+};
+
+/// How to round a value that lies between two multiples of a unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Always round down, i.e. truncate.
+    TowardZero,
+    /// Always round up.
+    AwayFromZero,
+    /// Round to the nearest multiple, ties are rounded up.
+    #[default]
+    HalfUp,
+    /// Round to the nearest multiple, ties are rounded down.
+    HalfDown,
+    /// Round to the nearest multiple, ties are rounded to the even multiple.
+    HalfEven,
+}
+
+/// Round `duration` to a multiple of `unit`.
+///
+/// Returns `None` if the result overflows. A zero `unit` leaves the value unchanged.
+pub(crate) fn round(duration: Duration, unit: Duration, mode: RoundingMode) -> Option<Duration> {
+    let unit = unit.as_nanos();
+    if unit == 0 {
+        return Some(duration);
+    }
+
+    let nanos = duration.as_nanos();
+    let (quotient, remainder) = (nanos / unit, nanos % unit);
+    let up = match mode {
+        _ if remainder == 0 => false,
+        RoundingMode::TowardZero => false,
+        RoundingMode::AwayFromZero => true,
+        RoundingMode::HalfUp => remainder * 2 >= unit,
+        RoundingMode::HalfDown => remainder * 2 > unit,
+        RoundingMode::HalfEven => match (remainder * 2).cmp(&unit) {
+            Ordering::Less => false,
+            Ordering::Equal => quotient % 2 == 1,
+            Ordering::Greater => true,
+        },
+    };
+
+    parse::nanos_to_duration((quotient + up as u128) * unit)
+}
+
+/// A [`std::time::Duration`] broken into calendar-free units,
+/// created by [`crate::time::DurationExt::decompose`].
+///
+/// Every field except `days` is less than the next larger unit,
+/// e.g. `hours` is always less than 24.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DurationParts {
+    pub days: u64,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+    pub millis: u32,
+    pub micros: u32,
+    pub nanos: u32,
+}
+
+impl DurationParts {
+    pub(crate) fn new(duration: Duration) -> Self {
+        let secs = duration.as_secs();
+        let subsec = duration.subsec_nanos();
+        Self {
+            days: secs / SECS_PER_DAY,
+            hours: (secs % SECS_PER_DAY / SECS_PER_HOUR) as u32,
+            minutes: (secs % SECS_PER_HOUR / SECS_PER_MINUTE) as u32,
+            seconds: (secs % SECS_PER_MINUTE) as u32,
+            millis: subsec / NANOS_PER_MILLI,
+            micros: subsec % NANOS_PER_MILLI / NANOS_PER_MICRO,
+            nanos: subsec % NANOS_PER_MICRO,
+        }
+    }
+
+    /// The value of a single unit.
+    ///
+    /// As the parts do not contain weeks, `TimeUnit::Week` returns the whole weeks in `days`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationExt, TimeUnit};
+    /// use std::time::Duration;
+    ///
+    /// let parts = Duration::from_secs(3_700).decompose();
+    /// assert_eq!(parts.get(TimeUnit::Minute), 1);
+    /// ```
+    pub fn get(&self, unit: TimeUnit) -> u64 {
+        match unit {
+            TimeUnit::Nanosecond => self.nanos as u64,
+            TimeUnit::Microsecond => self.micros as u64,
+            TimeUnit::Millisecond => self.millis as u64,
+            TimeUnit::Second => self.seconds as u64,
+            TimeUnit::Minute => self.minutes as u64,
+            TimeUnit::Hour => self.hours as u64,
+            TimeUnit::Day => self.days,
+            TimeUnit::Week => self.days / (SECS_PER_WEEK / SECS_PER_DAY),
+        }
+    }
+
+    /// Assemble the parts into a `Duration`.
+    ///
+    /// Fields are not required to be less than the next larger unit.
+    /// Returns `None` if the result overflows.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::DurationParts;
+    /// use std::time::Duration;
+    ///
+    /// let parts = DurationParts { minutes: 90, millis: 5, ..Default::default() };
+    /// assert_eq!(parts.to_duration(), Some(Duration::from_millis(5_400_005)));
+    /// ```
+    pub fn to_duration(&self) -> Option<Duration> {
+        // cannot overflow: `u64::MAX` days in nanoseconds is far below `u128::MAX`
+        let nanos = [
+            (self.days as u128, TimeUnit::Day),
+            (self.hours as u128, TimeUnit::Hour),
+            (self.minutes as u128, TimeUnit::Minute),
+            (self.seconds as u128, TimeUnit::Second),
+            (self.millis as u128, TimeUnit::Millisecond),
+            (self.micros as u128, TimeUnit::Microsecond),
+            (self.nanos as u128, TimeUnit::Nanosecond),
+        ]
+        .iter()
+        .map(|(value, unit)| value * unit.as_nanos())
+        .sum();
+        parse::nanos_to_duration(nanos)
+    }
+}
+
+impl From<TimeUnit> for Duration {
+    fn from(unit: TimeUnit) -> Self {
+        unit.as_duration()
+    }
+}
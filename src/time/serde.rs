@@ -0,0 +1,165 @@
+//! (De)serialization of [`std::time::Duration`] in different representations.
+//!
+//! Each module provides a `serialize` and a `deserialize` function,
+//! so it can be used with `#[serde(with = "...")]`:
+//!
+//! ```rust
+//! use rs_std_ext::time::DurationNumExt;
+//! use serde::{Deserialize, Serialize};
+//! use std::time::Duration;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "rs_std_ext::time::serde::human")]
+//!     timeout: Duration,
+//!     #[serde(with = "rs_std_ext::time::serde::millis")]
+//!     poll_interval: Duration,
+//! }
+//!
+//! let config: Config =
+//!     serde_json::from_str(r#"{ "timeout": "1m 30s", "poll_interval": 250 }"#).unwrap();
+//! assert_eq!(config.timeout, 90.seconds());
+//! assert_eq!(config.poll_interval, 250.milliseconds());
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&config).unwrap(),
+//!     r#"{"timeout":"1m 30s","poll_interval":250}"#
+//! );
+//! ```
+//!
+//! Errors of the string representations are [`ParseDurationError`](super::ParseDurationError)s.
+//!
+//! This module is only available with the `serde` feature.
+
+use std::fmt::Display;
+use std::time::Duration;
+
+use ::serde::de::Error as _;
+use ::serde::ser::Error as _;
+use ::serde::{Deserialize, Deserializer, Serializer};
+
+use super::parse::{parse_duration, ParseDurationError, ParseDurationErrorKind};
+use super::{extfn, DurationExt, DurationNumExtFallible};
+
+fn collect_str<S: Serializer>(value: impl Display, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&value)
+}
+
+/// Human readable strings, e.g. `"1h 30m 15.25s"`.
+///
+/// Serialization uses [`DurationExt::display_human`](super::DurationExt::display_human),
+/// deserialization accepts everything [`parse_duration`](super::extfn::parse_duration) does.
+pub mod human {
+    use super::*;
+
+    /// Serialize a `Duration` as a human readable string.
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        collect_str(duration.display_human(), serializer)
+    }
+
+    /// Deserialize a `Duration` from a string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        parse_duration(&s).map_err(D::Error::custom)
+    }
+}
+
+/// ISO 8601 duration strings without calendar units, e.g. `"PT1H30M15.25S"`.
+pub mod iso8601 {
+    use super::*;
+
+    /// Serialize a `Duration` as an ISO 8601 string.
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        collect_str(duration.display_iso8601(), serializer)
+    }
+
+    /// Deserialize a `Duration` from an ISO 8601 string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let start = s.len() - s.trim_start().len();
+        if !s.trim_start().starts_with('P') {
+            let kind = ParseDurationErrorKind::InvalidFormat;
+            return Err(D::Error::custom(ParseDurationError::new(kind, start)));
+        }
+        parse_duration(&s).map_err(D::Error::custom)
+    }
+}
+
+/// Whole seconds as an integer, e.g. `90`.
+///
+/// The fractional part is discarded on serialization.
+pub mod secs {
+    use super::*;
+
+    /// Serialize a `Duration` as whole seconds.
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_secs())
+    }
+
+    /// Deserialize a `Duration` from seconds.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        extfn::checked_from_secs(u64::deserialize(deserializer)?)
+            .ok_or_else(|| D::Error::custom("duration out of range"))
+    }
+}
+
+/// Whole milliseconds as an integer, e.g. `1500`.
+///
+/// The fractional part is discarded on serialization,
+/// durations of more than `u64::MAX` milliseconds fail to serialize.
+pub mod millis {
+    use super::*;
+
+    /// Serialize a `Duration` as whole milliseconds.
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        let millis = u64::try_from(duration.as_millis())
+            .map_err(|_| S::Error::custom("duration out of range for milliseconds"))?;
+        serializer.serialize_u64(millis)
+    }
+
+    /// Deserialize a `Duration` from milliseconds.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        extfn::checked_from_millis(u64::deserialize(deserializer)?)
+            .ok_or_else(|| D::Error::custom("duration out of range"))
+    }
+}
+
+/// Nanoseconds as an integer, e.g. `1500000000`.
+///
+/// Durations of more than `u64::MAX` nanoseconds (about 584 years) fail to serialize.
+pub mod nanos {
+    use super::*;
+
+    /// Serialize a `Duration` as nanoseconds.
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        let nanos = u64::try_from(duration.as_nanos())
+            .map_err(|_| S::Error::custom("duration out of range for nanoseconds"))?;
+        serializer.serialize_u64(nanos)
+    }
+
+    /// Deserialize a `Duration` from nanoseconds.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        extfn::checked_from_nanos(u64::deserialize(deserializer)?)
+            .ok_or_else(|| D::Error::custom("duration out of range"))
+    }
+}
+
+/// Seconds as a floating point number, e.g. `1.5`.
+///
+/// Integers are accepted on deserialization as well.
+/// Negative, infinite and `NaN` values are rejected.
+pub mod secs_f64 {
+    use super::*;
+
+    /// Serialize a `Duration` as floating point seconds.
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64())
+    }
+
+    /// Deserialize a `Duration` from floating point seconds.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let secs = f64::deserialize(deserializer)?;
+        DurationNumExtFallible::seconds(&secs)
+            .ok_or_else(|| D::Error::custom(format_args!("invalid duration: {secs} seconds")))
+    }
+}
@@ -0,0 +1,673 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{self, Write};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::{format, parse, DurationDisplay, DurationNumExtFallible, ParseDurationError};
+
+/// A signed [`std::time::Duration`].
+///
+/// The value consists of a sign and a `Duration` magnitude,
+/// so the range is symmetric: `-Duration::MAX..=Duration::MAX`.
+/// Zero is never negative.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::SignedDuration;
+/// use std::time::Duration;
+///
+/// let a = SignedDuration::from(Duration::from_secs(3));
+/// let b = SignedDuration::negative(Duration::from_secs(5));
+///
+/// assert_eq!(a + b, SignedDuration::negative(Duration::from_secs(2)));
+/// assert_eq!((a + b).abs(), SignedDuration::from(Duration::from_secs(2)));
+/// assert_eq!((a + b).signum(), -1);
+/// assert!(b < a);
+/// assert_eq!((a + b).to_string(), "-2s");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SignedDuration {
+    negative: bool,
+    magnitude: Duration,
+}
+
+impl SignedDuration {
+    /// A zero duration.
+    pub const ZERO: SignedDuration = SignedDuration {
+        negative: false,
+        magnitude: Duration::ZERO,
+    };
+
+    /// The maximum signed duration, equal to `Duration::MAX`.
+    pub const MAX: SignedDuration = SignedDuration {
+        negative: false,
+        magnitude: Duration::MAX,
+    };
+
+    /// The minimum signed duration, equal to `-Duration::MAX`.
+    pub const MIN: SignedDuration = SignedDuration {
+        negative: true,
+        magnitude: Duration::MAX,
+    };
+
+    /// Create a signed duration from a sign and a magnitude.
+    ///
+    /// If the magnitude is zero, the result is always non-negative.
+    pub const fn from_parts(negative: bool, magnitude: Duration) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    /// Create a non-negative signed duration.
+    pub const fn positive(magnitude: Duration) -> Self {
+        Self::from_parts(false, magnitude)
+    }
+
+    /// Create a non-positive signed duration.
+    pub const fn negative(magnitude: Duration) -> Self {
+        Self::from_parts(true, magnitude)
+    }
+
+    /// The signed difference `lhs - rhs` of two durations.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::SignedDuration;
+    /// use std::time::Duration;
+    ///
+    /// let d = SignedDuration::diff(Duration::from_secs(1), Duration::from_secs(3));
+    /// assert_eq!(d, SignedDuration::negative(Duration::from_secs(2)));
+    /// ```
+    pub fn diff(lhs: Duration, rhs: Duration) -> Self {
+        if lhs >= rhs {
+            Self::positive(lhs - rhs)
+        } else {
+            Self::negative(rhs - lhs)
+        }
+    }
+
+    /// Create a signed duration from a number of nanoseconds.
+    ///
+    /// Returns `None` if the value is out of range.
+    pub fn from_nanos(nanos: i128) -> Option<Self> {
+        parse::nanos_to_duration(nanos.unsigned_abs()).map(|d| Self::from_parts(nanos < 0, d))
+    }
+
+    /// Create a signed duration from a number of seconds, represented as `f64`.
+    ///
+    /// Returns `None` if the value is not finite or out of range.
+    pub fn try_from_secs_f64(secs: f64) -> Option<Self> {
+        Duration::try_from_secs_f64(secs.abs())
+            .ok()
+            .map(|d| Self::from_parts(secs.is_sign_negative(), d))
+    }
+
+    /// Returns `true` if the duration is less than zero.
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns `true` if the duration is greater than zero.
+    pub const fn is_positive(&self) -> bool {
+        !self.negative && !self.magnitude.is_zero()
+    }
+
+    /// Returns `true` if the duration is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of the duration.
+    pub const fn signum(&self) -> i32 {
+        if self.negative {
+            -1
+        } else if self.magnitude.is_zero() {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// The absolute value of the duration.
+    pub const fn abs(&self) -> Self {
+        Self {
+            negative: false,
+            magnitude: self.magnitude,
+        }
+    }
+
+    /// The absolute value of the duration, as an unsigned `Duration`.
+    pub const fn unsigned_abs(&self) -> Duration {
+        self.magnitude
+    }
+
+    /// The total number of whole nanoseconds.
+    pub const fn as_nanos(&self) -> i128 {
+        let nanos = self.magnitude.as_nanos() as i128;
+        if self.negative {
+            -nanos
+        } else {
+            nanos
+        }
+    }
+
+    /// The number of seconds, represented as `f64`.
+    pub fn as_secs_f64(&self) -> f64 {
+        let secs = self.magnitude.as_secs_f64();
+        if self.negative {
+            -secs
+        } else {
+            secs
+        }
+    }
+
+    /// Checked addition. Returns `None` if the result is out of range.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::SignedDuration;
+    /// use std::time::Duration;
+    ///
+    /// let one = SignedDuration::from(Duration::from_secs(1));
+    /// assert_eq!(one.checked_add(-one), Some(SignedDuration::ZERO));
+    /// assert_eq!(SignedDuration::MAX.checked_add(one), None);
+    /// ```
+    pub fn checked_add(self, rhs: SignedDuration) -> Option<Self> {
+        if self.negative == rhs.negative {
+            self.magnitude
+                .checked_add(rhs.magnitude)
+                .map(|d| Self::from_parts(self.negative, d))
+        } else if self.magnitude >= rhs.magnitude {
+            Some(Self::from_parts(
+                self.negative,
+                self.magnitude - rhs.magnitude,
+            ))
+        } else {
+            Some(Self::from_parts(
+                rhs.negative,
+                rhs.magnitude - self.magnitude,
+            ))
+        }
+    }
+
+    /// Checked subtraction. Returns `None` if the result is out of range.
+    pub fn checked_sub(self, rhs: SignedDuration) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+
+    /// Checked multiplication. Returns `None` if the result is out of range.
+    pub fn checked_mul(self, rhs: i32) -> Option<Self> {
+        self.magnitude
+            .checked_mul(rhs.unsigned_abs())
+            .map(|d| Self::from_parts(self.negative != (rhs < 0), d))
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: i32) -> Option<Self> {
+        self.magnitude
+            .checked_div(rhs.unsigned_abs())
+            .map(|d| Self::from_parts(self.negative != (rhs < 0), d))
+    }
+
+    /// Saturating addition. Returns [`SignedDuration::MAX`] or [`SignedDuration::MIN`]
+    /// if the result is out of range.
+    pub fn saturating_add(self, rhs: SignedDuration) -> Self {
+        self.checked_add(rhs)
+            .unwrap_or(if self.negative { Self::MIN } else { Self::MAX })
+    }
+
+    /// Saturating subtraction. Returns [`SignedDuration::MAX`] or [`SignedDuration::MIN`]
+    /// if the result is out of range.
+    pub fn saturating_sub(self, rhs: SignedDuration) -> Self {
+        self.saturating_add(-rhs)
+    }
+
+    /// Saturating multiplication. Returns [`SignedDuration::MAX`] or [`SignedDuration::MIN`]
+    /// if the result is out of range.
+    pub fn saturating_mul(self, rhs: i32) -> Self {
+        self.checked_mul(rhs)
+            .unwrap_or(if self.negative != (rhs < 0) {
+                Self::MIN
+            } else {
+                Self::MAX
+            })
+    }
+
+    /// Checked addition to an unsigned `Duration`.
+    ///
+    /// Returns `None` if the result is negative or overflows.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::SignedDuration;
+    /// use std::time::Duration;
+    ///
+    /// let d = SignedDuration::negative(Duration::from_secs(1));
+    /// assert_eq!(d.checked_add_to(Duration::from_secs(3)), Some(Duration::from_secs(2)));
+    /// assert_eq!(d.checked_add_to(Duration::ZERO), None);
+    /// ```
+    pub fn checked_add_to(self, duration: Duration) -> Option<Duration> {
+        if self.negative {
+            duration.checked_sub(self.magnitude)
+        } else {
+            duration.checked_add(self.magnitude)
+        }
+    }
+}
+
+impl fmt::Debug for SignedDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        fmt::Debug::fmt(&self.magnitude, f)
+    }
+}
+
+impl fmt::Display for SignedDuration {
+    /// Format the value like [`crate::time::DurationExt::display_human`],
+    /// with a leading `-` if it is negative, e.g. `-1m 30s`.
+    ///
+    /// The output can be parsed back with [`FromStr`].
+    /// The precision, width, fill and alignment are honored
+    /// the same way as for [`DurationDisplay`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rendered = String::new();
+        if self.negative {
+            rendered.push('-');
+        }
+        let magnitude = DurationDisplay::human(self.magnitude);
+        match f.precision() {
+            Some(precision) => write!(rendered, "{:.*}", precision, magnitude)?,
+            None => write!(rendered, "{}", magnitude)?,
+        }
+        format::pad(f, &rendered)
+    }
+}
+
+impl PartialOrd for SignedDuration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SignedDuration {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl From<Duration> for SignedDuration {
+    fn from(value: Duration) -> Self {
+        Self::positive(value)
+    }
+}
+
+/// The error returned when converting a negative [`SignedDuration`] to a `Duration`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NegativeDurationError;
+
+impl fmt::Display for NegativeDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cannot convert a negative duration to `Duration`")
+    }
+}
+
+impl Error for NegativeDurationError {}
+
+impl TryFrom<SignedDuration> for Duration {
+    type Error = NegativeDurationError;
+
+    fn try_from(value: SignedDuration) -> Result<Self, Self::Error> {
+        if value.negative {
+            Err(NegativeDurationError)
+        } else {
+            Ok(value.magnitude)
+        }
+    }
+}
+
+impl FromStr for SignedDuration {
+    type Err = ParseDurationError;
+
+    /// Parse a signed duration, i.e. any format accepted by
+    /// [`crate::time::extfn::parse_duration`] with an optional leading `-` or `+`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim_start();
+        let (negative, rest) = match trimmed.as_bytes().first() {
+            Some(b'-') => (true, &trimmed[1..]),
+            Some(b'+') => (false, &trimmed[1..]),
+            _ => (false, trimmed),
+        };
+        let offset = s.len() - rest.len();
+        parse::parse_duration(rest)
+            .map(|d| Self::from_parts(negative, d))
+            .map_err(|e| e.offset_by(offset))
+    }
+}
+
+impl Neg for SignedDuration {
+    type Output = SignedDuration;
+
+    fn neg(self) -> Self::Output {
+        Self::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Add for SignedDuration {
+    type Output = SignedDuration;
+
+    fn add(self, rhs: SignedDuration) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("overflow when adding signed durations")
+    }
+}
+
+impl Sub for SignedDuration {
+    type Output = SignedDuration;
+
+    fn sub(self, rhs: SignedDuration) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting signed durations")
+    }
+}
+
+impl Add<Duration> for SignedDuration {
+    type Output = SignedDuration;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        self + SignedDuration::from(rhs)
+    }
+}
+
+impl Sub<Duration> for SignedDuration {
+    type Output = SignedDuration;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self - SignedDuration::from(rhs)
+    }
+}
+
+impl Mul<i32> for SignedDuration {
+    type Output = SignedDuration;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("overflow when multiplying signed duration by scalar")
+    }
+}
+
+impl Mul<SignedDuration> for i32 {
+    type Output = SignedDuration;
+
+    fn mul(self, rhs: SignedDuration) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Div<i32> for SignedDuration {
+    type Output = SignedDuration;
+
+    fn div(self, rhs: i32) -> Self::Output {
+        self.checked_div(rhs)
+            .expect("divide by zero error when dividing signed duration by scalar")
+    }
+}
+
+impl AddAssign for SignedDuration {
+    fn add_assign(&mut self, rhs: SignedDuration) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for SignedDuration {
+    fn sub_assign(&mut self, rhs: SignedDuration) {
+        *self = *self - rhs;
+    }
+}
+
+impl AddAssign<Duration> for SignedDuration {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Duration> for SignedDuration {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign<i32> for SignedDuration {
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<i32> for SignedDuration {
+    fn div_assign(&mut self, rhs: i32) {
+        *self = *self / rhs;
+    }
+}
+
+impl Sum for SignedDuration {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, d| acc + d)
+    }
+}
+
+impl<'a> Sum<&'a SignedDuration> for SignedDuration {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, d| acc + *d)
+    }
+}
+
+/// Extension methods for constructing [`SignedDuration`] with numbers.
+///
+/// This is the signed counterpart of [`crate::time::DurationNumExt`].
+/// Both traits share the same method names,
+/// so usually only one of them should be imported.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{SignedDuration, SignedDurationNumExt};
+/// use std::time::Duration;
+///
+/// assert_eq!((-5i32).seconds(), SignedDuration::negative(Duration::from_secs(5)));
+/// assert_eq!(1.5f64.minutes(), SignedDuration::from(Duration::from_secs(90)));
+/// ```
+pub trait SignedDurationNumExt: SignedDurationNumExtFallible {
+    /// Create a `SignedDuration`, using the postfix syntax.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the number is too large.
+    fn seconds(&self) -> SignedDuration {
+        SignedDurationNumExtFallible::seconds(self).unwrap()
+    }
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the number is too large.
+    fn milliseconds(&self) -> SignedDuration {
+        SignedDurationNumExtFallible::milliseconds(self).unwrap()
+    }
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the number is too large.
+    fn microseconds(&self) -> SignedDuration {
+        SignedDurationNumExtFallible::microseconds(self).unwrap()
+    }
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the number is too large.
+    fn nanoseconds(&self) -> SignedDuration {
+        SignedDurationNumExtFallible::nanoseconds(self).unwrap()
+    }
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the number is too large.
+    fn minutes(&self) -> SignedDuration {
+        SignedDurationNumExtFallible::minutes(self).unwrap()
+    }
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the number is too large.
+    fn hours(&self) -> SignedDuration {
+        SignedDurationNumExtFallible::hours(self).unwrap()
+    }
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the number is too large.
+    fn days(&self) -> SignedDuration {
+        SignedDurationNumExtFallible::days(self).unwrap()
+    }
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the number is too large.
+    fn weeks(&self) -> SignedDuration {
+        SignedDurationNumExtFallible::weeks(self).unwrap()
+    }
+}
+
+/// Extension methods for constructing [`SignedDuration`] with numbers.
+///
+/// This is the signed counterpart of [`crate::time::DurationNumExtFallible`],
+/// and only returns `None` if the number is too large.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{SignedDuration, SignedDurationNumExtFallible};
+/// use std::time::Duration;
+///
+/// assert_eq!((-5i128).seconds(), Some(SignedDuration::negative(Duration::from_secs(5))));
+/// assert!(i128::MIN.seconds().is_none());
+/// ```
+pub trait SignedDurationNumExtFallible {
+    /// Create a `SignedDuration`, using the postfix syntax.
+    fn seconds(&self) -> Option<SignedDuration>;
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    fn milliseconds(&self) -> Option<SignedDuration>;
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    fn microseconds(&self) -> Option<SignedDuration>;
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    fn nanoseconds(&self) -> Option<SignedDuration>;
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    fn minutes(&self) -> Option<SignedDuration>;
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    fn hours(&self) -> Option<SignedDuration>;
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    fn days(&self) -> Option<SignedDuration>;
+
+    /// Create a `SignedDuration`, using the postfix syntax.
+    fn weeks(&self) -> Option<SignedDuration>;
+}
+
+/// Implement `SignedDurationNumExtFallible` by splitting the number into
+/// a sign and a magnitude, and delegating the magnitude to `DurationNumExtFallible`.
+macro_rules! __impl_signed_fallible {
+    ($ty:ty, |$v:ident| $negative:expr, $magnitude:expr) => {
+        impl SignedDurationNumExtFallible for $ty {
+            __impl_signed_fallible!(
+                @methods |$v| $negative, $magnitude;
+                seconds, milliseconds, microseconds, nanoseconds, minutes, hours, days, weeks
+            );
+        }
+    };
+
+    (@methods |$v:ident| $negative:expr, $magnitude:expr; $($method:ident),*) => {
+        $(
+            #[inline]
+            fn $method(&self) -> Option<SignedDuration> {
+                let $v = self;
+                DurationNumExtFallible::$method(&$magnitude)
+                    .map(|d| SignedDuration::from_parts($negative, d))
+            }
+        )*
+    };
+}
+
+macro_rules! __impl_signed_int {
+    ($($ty:ty),*) => {
+        $(
+            __impl_signed_fallible!($ty, |v| *v < 0, v.unsigned_abs());
+            impl SignedDurationNumExt for $ty {}
+        )*
+    };
+}
+
+macro_rules! __impl_unsigned_int {
+    ($($ty:ty),*) => {
+        $(
+            __impl_signed_fallible!($ty, |v| false, *v);
+            impl SignedDurationNumExt for $ty {}
+        )*
+    };
+}
+
+macro_rules! __impl_float {
+    ($($ty:ty),*) => {
+        $(
+            __impl_signed_fallible!($ty, |v| v.is_sign_negative(), v.abs());
+            impl SignedDurationNumExt for $ty {}
+        )*
+    };
+}
+
+__impl_signed_int!(i8, i16, i32, i64, i128);
+__impl_unsigned_int!(u8, u16, u32, u64, u128);
+__impl_float!(f32, f64);
+
+#[cfg(feature = "crate-num")]
+mod __num_impl {
+    use num::bigint::Sign;
+    use num::{BigInt, BigRational, BigUint, Signed};
+
+    use super::{DurationNumExtFallible, SignedDuration, SignedDurationNumExtFallible};
+
+    __impl_signed_fallible!(BigInt, |v| v.sign() == Sign::Minus, *v.magnitude());
+    __impl_signed_fallible!(BigUint, |v| false, *v);
+    __impl_signed_fallible!(BigRational, |v| v.is_negative(), v.abs());
+}
@@ -0,0 +1,421 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use super::parse::nanos_to_duration;
+use super::NANOS_PER_SEC;
+
+/// The number of samples kept for exact percentiles by default.
+const DEFAULT_EXACT_LIMIT: usize = 1024;
+
+/// Values below `2^SUB_BUCKET_BITS` nanoseconds get a bucket each,
+/// larger values share buckets of a relative width of at most `2^-(SUB_BUCKET_BITS - 1)`.
+const SUB_BUCKET_BITS: u32 = 8;
+
+/// An accumulator of `Duration` samples, e.g. request latencies or benchmark timings.
+///
+/// The count, sum, min, max and mean are always exact.
+/// With the `crate-num` feature, the variance and standard deviation are derived
+/// from an exact sum of squares; without it, they are computed in `f64`
+/// and may lose nanosecond precision for large samples.
+/// Percentiles are exact as long as at most [`DurationStats::with_exact_limit`] samples
+/// have been recorded; beyond that, the samples are moved into a logarithmic histogram,
+/// and percentiles are accurate to about 0.4%.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{DurationNumExt, DurationStats};
+///
+/// let stats: DurationStats = [10, 20, 30, 40].iter().map(|ms| ms.milliseconds()).collect();
+///
+/// assert_eq!(stats.count(), 4);
+/// assert_eq!(stats.min(), Some(10.milliseconds()));
+/// assert_eq!(stats.max(), Some(40.milliseconds()));
+/// assert_eq!(stats.mean(), Some(25.milliseconds()));
+/// assert_eq!(stats.median(), Some(20.milliseconds()));
+/// assert_eq!(stats.percentile(99.0), Some(40.milliseconds()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DurationStats {
+    count: u64,
+    // the sum is kept split, so that it can never overflow
+    sum_secs: u128,
+    sum_nanos: u128,
+    min: Option<Duration>,
+    max: Option<Duration>,
+    squares: Squares,
+    exact_limit: usize,
+    samples: Samples,
+}
+
+#[derive(Debug, Clone)]
+enum Samples {
+    /// The samples in ascending order.
+    Exact(Vec<Duration>),
+    /// Sample counts by bucket index.
+    Histogram(BTreeMap<usize, u64>),
+}
+
+impl Default for DurationStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DurationStats {
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            sum_secs: 0,
+            sum_nanos: 0,
+            min: None,
+            max: None,
+            squares: Squares::default(),
+            exact_limit: DEFAULT_EXACT_LIMIT,
+            samples: Samples::Exact(Vec::new()),
+        }
+    }
+
+    /// Keep up to `limit` samples for exact percentiles, instead of the default of 1024.
+    ///
+    /// If more samples have already been recorded, they are moved into the histogram.
+    pub fn with_exact_limit(mut self, limit: usize) -> Self {
+        self.exact_limit = limit;
+        self.fit_exact_limit();
+        self
+    }
+
+    /// Record a sample.
+    pub fn record(&mut self, sample: Duration) {
+        self.count += 1;
+        self.sum_secs += sample.as_secs() as u128;
+        self.sum_nanos += sample.subsec_nanos() as u128;
+        self.min = Some(self.min.map_or(sample, |min| min.min(sample)));
+        self.max = Some(self.max.map_or(sample, |max| max.max(sample)));
+
+        self.squares.record(sample.as_nanos(), self.count);
+
+        match &mut self.samples {
+            Samples::Exact(samples) => {
+                let index = samples.partition_point(|s| *s <= sample);
+                samples.insert(index, sample);
+            }
+            Samples::Histogram(buckets) => {
+                *buckets.entry(bucket_index(sample.as_nanos())).or_default() += 1;
+            }
+        }
+        self.fit_exact_limit();
+    }
+
+    /// Add all samples of `other` to `self`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationNumExt, DurationStats};
+    ///
+    /// let mut a: DurationStats = [1, 2].iter().map(|s| s.seconds()).collect();
+    /// let b: DurationStats = [3, 4, 5].iter().map(|s| s.seconds()).collect();
+    /// a.merge(&b);
+    ///
+    /// assert_eq!(a.count(), 5);
+    /// assert_eq!(a.mean(), Some(3.seconds()));
+    /// assert_eq!(a.median(), Some(3.seconds()));
+    /// assert_eq!(a.max(), Some(5.seconds()));
+    /// ```
+    pub fn merge(&mut self, other: &DurationStats) {
+        if other.count == 0 {
+            return;
+        }
+
+        self.squares.merge(&other.squares, self.count, other.count);
+        self.count += other.count;
+        self.sum_secs += other.sum_secs;
+        self.sum_nanos += other.sum_nanos;
+        self.min = self.min.into_iter().chain(other.min).min();
+        self.max = self.max.into_iter().chain(other.max).max();
+
+        match (&mut self.samples, &other.samples) {
+            (Samples::Exact(samples), Samples::Exact(others)) => {
+                samples.extend_from_slice(others);
+                samples.sort_unstable();
+            }
+            (Samples::Exact(samples), Samples::Histogram(others)) => {
+                let mut buckets = others.clone();
+                add_to_histogram(&mut buckets, samples);
+                self.samples = Samples::Histogram(buckets);
+            }
+            (Samples::Histogram(buckets), Samples::Exact(others)) => {
+                add_to_histogram(buckets, others);
+            }
+            (Samples::Histogram(buckets), Samples::Histogram(others)) => {
+                for (index, n) in others {
+                    *buckets.entry(*index).or_default() += n;
+                }
+            }
+        }
+        self.fit_exact_limit();
+    }
+
+    /// The number of samples.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns `true` if no samples have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns `true` if percentiles are exact, i.e. all samples are kept.
+    pub fn is_exact(&self) -> bool {
+        matches!(self.samples, Samples::Exact(_))
+    }
+
+    /// The smallest sample.
+    pub fn min(&self) -> Option<Duration> {
+        self.min
+    }
+
+    /// The largest sample.
+    pub fn max(&self) -> Option<Duration> {
+        self.max
+    }
+
+    /// The sum of all samples, or `None` if it is not representable as a `Duration`.
+    pub fn sum(&self) -> Option<Duration> {
+        let secs = self.sum_secs + self.sum_nanos / NANOS_PER_SEC as u128;
+        let nanos = (self.sum_nanos % NANOS_PER_SEC as u128) as u32;
+        Some(Duration::new(secs.try_into().ok()?, nanos))
+    }
+
+    /// The arithmetic mean of the samples, rounded down to the nanosecond.
+    ///
+    /// Returns `None` if no samples have been recorded.
+    pub fn mean(&self) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+        let count = self.count as u128;
+        // `remainder * NANOS_PER_SEC` cannot overflow, as the remainder is below `u64::MAX`
+        let remainder = self.sum_secs % count * NANOS_PER_SEC as u128 + self.sum_nanos;
+        let secs = self.sum_secs / count + remainder / count / NANOS_PER_SEC as u128;
+        let nanos = (remainder / count % NANOS_PER_SEC as u128) as u32;
+        // the mean never exceeds the largest sample
+        Some(Duration::new(secs as u64, nanos))
+    }
+
+    /// The population variance of the samples, in square nanoseconds.
+    ///
+    /// With the `crate-num` feature, this is the exact variance rounded to an `f64`.
+    ///
+    /// Returns `None` if no samples have been recorded.
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| {
+            self.squares
+                .variance(self.count, self.sum_secs, self.sum_nanos)
+        })
+    }
+
+    /// The population standard deviation of the samples, rounded to the nanosecond.
+    ///
+    /// With the `crate-num` feature, the rounding is exact.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationNumExt, DurationStats};
+    ///
+    /// let stats: DurationStats = [2, 4, 4, 4, 5, 5, 7, 9].iter().map(|ms| ms.milliseconds()).collect();
+    /// assert_eq!(stats.std_dev(), Some(2.milliseconds()));
+    /// ```
+    pub fn std_dev(&self) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+        nanos_to_duration(
+            self.squares
+                .std_dev_nanos(self.count, self.sum_secs, self.sum_nanos),
+        )
+    }
+
+    /// The `p`-th percentile of the samples, using the nearest-rank method.
+    ///
+    /// Returns `None` if no samples have been recorded, or if `p` is not in `[0, 100]`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationNumExt, DurationStats};
+    ///
+    /// let stats: DurationStats = (1..=100u64).map(|ms| ms.milliseconds()).collect();
+    /// assert_eq!(stats.percentile(0.0), Some(1.milliseconds()));
+    /// assert_eq!(stats.percentile(95.0), Some(95.milliseconds()));
+    /// assert_eq!(stats.percentile(99.9), Some(100.milliseconds()));
+    /// assert_eq!(stats.percentile(101.0), None);
+    /// ```
+    pub fn percentile(&self, p: f64) -> Option<Duration> {
+        if self.count == 0 || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let rank = ((p / 100.0 * self.count as f64).ceil() as u64).clamp(1, self.count);
+
+        match &self.samples {
+            Samples::Exact(samples) => samples.get(rank as usize - 1).copied(),
+            // the extremes are known exactly
+            Samples::Histogram(_) if rank == 1 => self.min,
+            Samples::Histogram(_) if rank == self.count => self.max,
+            Samples::Histogram(buckets) => {
+                let mut seen = 0;
+                let (index, _) = buckets.iter().find(|(_, n)| {
+                    seen += **n;
+                    seen >= rank
+                })?;
+                let (low, width) = bucket_bounds(*index);
+                let value = nanos_to_duration(low + (width - 1) / 2)?;
+                Some(value.clamp(self.min?, self.max?))
+            }
+        }
+    }
+
+    /// The median of the samples, i.e. the 50th percentile.
+    pub fn median(&self) -> Option<Duration> {
+        self.percentile(50.0)
+    }
+
+    /// Move the samples into the histogram if there are too many of them.
+    fn fit_exact_limit(&mut self) {
+        if let Samples::Exact(samples) = &self.samples {
+            if samples.len() > self.exact_limit {
+                let mut buckets = BTreeMap::new();
+                add_to_histogram(&mut buckets, samples);
+                self.samples = Samples::Histogram(buckets);
+            }
+        }
+    }
+}
+
+impl Extend<Duration> for DurationStats {
+    fn extend<T: IntoIterator<Item = Duration>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|sample| self.record(sample));
+    }
+}
+
+impl FromIterator<Duration> for DurationStats {
+    fn from_iter<T: IntoIterator<Item = Duration>>(iter: T) -> Self {
+        let mut stats = Self::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+/// The exact sum of the squared samples in nanoseconds.
+///
+/// Together with the count and the sum, this gives the exact variance:
+/// `n² · variance = n · Σx² - (Σx)²`.
+#[cfg(feature = "crate-num")]
+#[derive(Debug, Clone, Default)]
+struct Squares(num::BigUint);
+
+#[cfg(feature = "crate-num")]
+impl Squares {
+    fn record(&mut self, nanos: u128, _count: u64) {
+        let nanos = num::BigUint::from(nanos);
+        self.0 += &nanos * &nanos;
+    }
+
+    fn merge(&mut self, other: &Squares, _count: u64, _other_count: u64) {
+        self.0 += &other.0;
+    }
+
+    /// `n² · variance`
+    fn scaled_variance(&self, count: u64, sum_secs: u128, sum_nanos: u128) -> num::BigUint {
+        let sum = num::BigUint::from(sum_secs) * NANOS_PER_SEC + sum_nanos;
+        // cannot underflow, by the Cauchy-Schwarz inequality
+        &self.0 * count - &sum * &sum
+    }
+
+    fn variance(&self, count: u64, sum_secs: u128, sum_nanos: u128) -> f64 {
+        use num::{BigRational, ToPrimitive};
+
+        let scaled = self.scaled_variance(count, sum_secs, sum_nanos);
+        let squared_count = num::BigUint::from(count).pow(2);
+        BigRational::new(scaled.into(), squared_count.into())
+            .to_f64()
+            .unwrap_or(f64::INFINITY)
+    }
+
+    fn std_dev_nanos(&self, count: u64, sum_secs: u128, sum_nanos: u128) -> u128 {
+        use num::ToPrimitive;
+
+        // `round(sqrt(v) / n) = floor((floor(sqrt(4v)) + n) / 2n)` for `v = n² · variance`
+        let scaled = self.scaled_variance(count, sum_secs, sum_nanos);
+        let root = (scaled * 4u8).sqrt();
+        // the standard deviation never exceeds the largest sample
+        ((root + count) / (2 * count as u128)).to_u128().unwrap()
+    }
+}
+
+/// The running mean and sum of squared deviations in nanoseconds (Welford's algorithm).
+#[cfg(not(feature = "crate-num"))]
+#[derive(Debug, Clone, Default)]
+struct Squares {
+    mean: f64,
+    m2: f64,
+}
+
+#[cfg(not(feature = "crate-num"))]
+impl Squares {
+    fn record(&mut self, nanos: u128, count: u64) {
+        let nanos = nanos as f64;
+        let delta = nanos - self.mean;
+        self.mean += delta / count as f64;
+        self.m2 += delta * (nanos - self.mean);
+    }
+
+    fn merge(&mut self, other: &Squares, count: u64, other_count: u64) {
+        let total = (count + other_count) as f64;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other_count as f64 / total;
+        self.m2 += other.m2 + delta * delta * count as f64 * other_count as f64 / total;
+    }
+
+    fn variance(&self, count: u64, _sum_secs: u128, _sum_nanos: u128) -> f64 {
+        self.m2 / count as f64
+    }
+
+    fn std_dev_nanos(&self, count: u64, sum_secs: u128, sum_nanos: u128) -> u128 {
+        self.variance(count, sum_secs, sum_nanos).sqrt().round() as u128
+    }
+}
+
+fn add_to_histogram(buckets: &mut BTreeMap<usize, u64>, samples: &[Duration]) {
+    for sample in samples {
+        *buckets.entry(bucket_index(sample.as_nanos())).or_default() += 1;
+    }
+}
+
+/// The histogram bucket of a value in nanoseconds.
+///
+/// Small values are their own bucket, larger ones are bucketed by
+/// their highest `SUB_BUCKET_BITS` bits.
+fn bucket_index(nanos: u128) -> usize {
+    if nanos < 1 << SUB_BUCKET_BITS {
+        return nanos as usize;
+    }
+    let shift = u128::BITS - nanos.leading_zeros() - SUB_BUCKET_BITS;
+    let mantissa = (nanos >> shift) as usize - (1 << (SUB_BUCKET_BITS - 1));
+    (1 << SUB_BUCKET_BITS) + ((shift as usize - 1) << (SUB_BUCKET_BITS - 1)) + mantissa
+}
+
+/// The lowest value and the width of a histogram bucket.
+fn bucket_bounds(index: usize) -> (u128, u128) {
+    if index < 1 << SUB_BUCKET_BITS {
+        return (index as u128, 1);
+    }
+    let index = index - (1 << SUB_BUCKET_BITS);
+    let shift = (index >> (SUB_BUCKET_BITS - 1)) + 1;
+    let mantissa = (index & ((1 << (SUB_BUCKET_BITS - 1)) - 1)) + (1 << (SUB_BUCKET_BITS - 1));
+    ((mantissa as u128) << shift, 1 << shift)
+}
@@ -0,0 +1,360 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use super::{Clock, SystemClock, TimePoint, Timestamp};
+
+/// A pausable stopwatch that measures elapsed time and laps.
+///
+/// Time spent while the stopwatch is stopped is not counted.
+///
+/// A `Stopwatch<Timestamp>` is measured on a [`Clock`] instead,
+/// see the `*_on` methods, e.g. [`Stopwatch::start_new_on`].
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{DurationExt, Stopwatch};
+///
+/// let mut sw = Stopwatch::start_new();
+/// // ... first phase
+/// let first = sw.lap();
+/// sw.stop();
+/// // ... not measured
+/// sw.start();
+/// // ... second phase
+/// let second = sw.lap();
+///
+/// assert_eq!(sw.laps(), [first, second]);
+/// assert!(first + second <= sw.elapsed());
+/// println!("took {}", sw.elapsed().display_human());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stopwatch<T = Instant> {
+    /// When the current run started, if running.
+    started: Option<T>,
+    /// The time accumulated by previous runs.
+    accumulated: Duration,
+    /// The elapsed time at the end of the last lap.
+    lap_start: Duration,
+    laps: Vec<Duration>,
+}
+
+impl<T> Default for Stopwatch<T> {
+    fn default() -> Self {
+        Self {
+            started: None,
+            accumulated: Duration::ZERO,
+            lap_start: Duration::ZERO,
+            laps: Vec::new(),
+        }
+    }
+}
+
+impl<T: TimePoint> Stopwatch<T> {
+    /// Start or resume the stopwatch at `now`. Does nothing if it is already running.
+    pub fn start_at(&mut self, now: T) {
+        self.started.get_or_insert(now);
+    }
+
+    /// Pause the stopwatch at `now`, and return the total elapsed time.
+    ///
+    /// Does nothing if it is already stopped.
+    pub fn stop_at(&mut self, now: T) -> Duration {
+        if let Some(started) = self.started.take() {
+            self.accumulated += now.saturating_duration_since(started);
+        }
+        self.accumulated
+    }
+
+    /// Returns `true` if the stopwatch is running.
+    pub fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    /// The total elapsed time at `now`, excluding the time the stopwatch was stopped.
+    pub fn elapsed_at(&self, now: T) -> Duration {
+        self.accumulated
+            + self
+                .started
+                .map_or(Duration::ZERO, |s| now.saturating_duration_since(s))
+    }
+
+    /// The time from the previous lap (or the start) to `now`, without ending the lap.
+    pub fn split_at(&self, now: T) -> Duration {
+        self.elapsed_at(now).saturating_sub(self.lap_start)
+    }
+
+    /// End the current lap at `now`, record it and return its length.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationNumExt, Stopwatch};
+    /// use std::time::Instant;
+    ///
+    /// let now = Instant::now();
+    /// let mut sw = Stopwatch::new();
+    /// sw.start_at(now);
+    /// assert_eq!(sw.lap_at(now + 2.seconds()), 2.seconds());
+    /// assert_eq!(sw.stop_at(now + 3.seconds()), 3.seconds());
+    /// assert_eq!(sw.split_at(now + 10.seconds()), 1.seconds());
+    /// ```
+    pub fn lap_at(&mut self, now: T) -> Duration {
+        let elapsed = self.elapsed_at(now);
+        let lap = elapsed.saturating_sub(self.lap_start);
+        self.lap_start = elapsed;
+        self.laps.push(lap);
+        lap
+    }
+
+    /// The recorded laps, in order.
+    pub fn laps(&self) -> &[Duration] {
+        &self.laps
+    }
+
+    /// Stop the stopwatch, and clear the elapsed time and the laps.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Reset the stopwatch and start it again at `now`.
+    pub fn restart_at(&mut self, now: T) {
+        self.reset();
+        self.start_at(now);
+    }
+}
+
+impl Stopwatch {
+    /// Create a stopped stopwatch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a running stopwatch.
+    pub fn start_new() -> Self {
+        let mut sw = Self::new();
+        sw.start();
+        sw
+    }
+
+    /// Start or resume the stopwatch. Does nothing if it is already running.
+    pub fn start(&mut self) {
+        self.start_at(Instant::now());
+    }
+
+    /// Pause the stopwatch, and return the total elapsed time.
+    ///
+    /// Does nothing if it is already stopped.
+    pub fn stop(&mut self) -> Duration {
+        self.stop_at(Instant::now())
+    }
+
+    /// The total elapsed time, excluding the time the stopwatch was stopped.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed_at(Instant::now())
+    }
+
+    /// The time since the previous lap (or the start), without ending the lap.
+    pub fn split(&self) -> Duration {
+        self.split_at(Instant::now())
+    }
+
+    /// End the current lap, record it and return its length.
+    pub fn lap(&mut self) -> Duration {
+        self.lap_at(Instant::now())
+    }
+
+    /// Reset the stopwatch and start it again.
+    pub fn restart(&mut self) {
+        self.restart_at(Instant::now());
+    }
+}
+
+impl Stopwatch<Timestamp> {
+    /// Create a stopwatch running on `clock`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationNumExt, ManualClock, Stopwatch};
+    ///
+    /// let clock = ManualClock::new();
+    /// let mut sw = Stopwatch::start_new_on(&clock);
+    ///
+    /// clock.advance(2.seconds());
+    /// assert_eq!(sw.lap_on(&clock), 2.seconds());
+    /// sw.stop_on(&clock);
+    ///
+    /// clock.advance(1.minutes());
+    /// assert_eq!(sw.elapsed_on(&clock), 2.seconds());
+    /// ```
+    pub fn start_new_on(clock: &impl Clock) -> Self {
+        let mut sw = Self::default();
+        sw.start_on(clock);
+        sw
+    }
+
+    /// Start or resume the stopwatch on `clock`. Does nothing if it is already running.
+    pub fn start_on(&mut self, clock: &impl Clock) {
+        self.start_at(clock.now());
+    }
+
+    /// Pause the stopwatch on `clock`, and return the total elapsed time.
+    ///
+    /// Does nothing if it is already stopped.
+    pub fn stop_on(&mut self, clock: &impl Clock) -> Duration {
+        self.stop_at(clock.now())
+    }
+
+    /// The total elapsed time on `clock`, excluding the time the stopwatch was stopped.
+    pub fn elapsed_on(&self, clock: &impl Clock) -> Duration {
+        self.elapsed_at(clock.now())
+    }
+
+    /// The time since the previous lap (or the start) on `clock`, without ending the lap.
+    pub fn split_on(&self, clock: &impl Clock) -> Duration {
+        self.split_at(clock.now())
+    }
+
+    /// End the current lap on `clock`, record it and return its length.
+    pub fn lap_on(&mut self, clock: &impl Clock) -> Duration {
+        self.lap_at(clock.now())
+    }
+
+    /// Reset the stopwatch and start it again on `clock`.
+    pub fn restart_on(&mut self, clock: &impl Clock) {
+        self.restart_at(clock.now());
+    }
+}
+
+impl fmt::Display for Stopwatch {
+    /// Format the elapsed time like [`DurationExt::display_human`](super::DurationExt::display_human).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&super::DurationExt::display_human(&self.elapsed()), f)
+    }
+}
+
+/// Run `f`, and return its result together with the time it took.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{time_it, DurationNumExt};
+///
+/// let (sum, took) = time_it(|| (1..=100u32).sum::<u32>());
+/// assert_eq!(sum, 5050);
+/// assert!(took < 1.seconds());
+/// ```
+pub fn time_it<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+/// Like [`time_it`], but measures the time on `clock`.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{time_it_on, DurationNumExt, ManualClock};
+///
+/// let clock = ManualClock::new();
+/// let (value, took) = time_it_on(&clock, || {
+///     clock.advance(3.seconds());
+///     42
+/// });
+/// assert_eq!(value, 42);
+/// assert_eq!(took, 3.seconds());
+/// ```
+pub fn time_it_on<T>(clock: &impl Clock, f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = clock.now();
+    let value = f();
+    (value, clock.elapsed(start))
+}
+
+/// A guard that reports the time it was alive for when dropped.
+///
+/// The time is measured on a [`Clock`], see [`TimedGuard::new_on`].
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{DurationExt, TimedGuard};
+/// use std::cell::Cell;
+/// use std::time::Duration;
+///
+/// let took = Cell::new(None);
+/// {
+///     let _guard = TimedGuard::new(|d: Duration| took.set(Some(d)));
+///     // ... the timed scope
+/// }
+/// assert!(took.get().is_some());
+///
+/// // or just log it
+/// let _guard = TimedGuard::new(|d: Duration| println!("done in {}", d.display_human()));
+/// ```
+pub struct TimedGuard<F: FnOnce(Duration), C: Clock = SystemClock> {
+    clock: C,
+    start: Timestamp,
+    report: Option<F>,
+}
+
+impl<F: FnOnce(Duration)> TimedGuard<F> {
+    /// Start timing, calling `report` with the elapsed time on drop.
+    pub fn new(report: F) -> Self {
+        Self::new_on(SystemClock, report)
+    }
+}
+
+impl<F: FnOnce(Duration), C: Clock> TimedGuard<F, C> {
+    /// Start timing on `clock`, calling `report` with the elapsed time on drop.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{DurationNumExt, ManualClock, TimedGuard};
+    /// use std::cell::Cell;
+    ///
+    /// let clock = ManualClock::new();
+    /// let took = Cell::new(None);
+    /// {
+    ///     let _guard = TimedGuard::new_on(&clock, |d| took.set(Some(d)));
+    ///     clock.advance(5.seconds());
+    /// }
+    /// assert_eq!(took.get(), Some(5.seconds()));
+    /// ```
+    pub fn new_on(clock: C, report: F) -> Self {
+        Self {
+            start: clock.now(),
+            clock,
+            report: Some(report),
+        }
+    }
+
+    /// The time elapsed since the guard was created.
+    pub fn elapsed(&self) -> Duration {
+        self.clock.elapsed(self.start)
+    }
+
+    /// Drop the guard without reporting.
+    pub fn cancel(mut self) {
+        self.report = None;
+    }
+}
+
+impl<F: FnOnce(Duration), C: Clock> fmt::Debug for TimedGuard<F, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimedGuard")
+            .field("start", &self.start)
+            .field("armed", &self.report.is_some())
+            .finish()
+    }
+}
+
+impl<F: FnOnce(Duration), C: Clock> Drop for TimedGuard<F, C> {
+    fn drop(&mut self) {
+        if let Some(report) = self.report.take() {
+            report(self.elapsed());
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::{extfn, SignedDuration, SECS_PER_DAY};
+
+/// Extension methods for [`std::time::SystemTime`].
+///
+/// All conversions are relative to the [`UNIX_EPOCH`] and never panic.
+pub trait SystemTimeExt {
+    /// The signed offset from the unix epoch.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::{SignedDuration, SystemTimeExt};
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let t = UNIX_EPOCH - Duration::from_secs(5);
+    /// assert_eq!(t.unix_offset(), SignedDuration::negative(Duration::from_secs(5)));
+    /// ```
+    fn unix_offset(&self) -> SignedDuration;
+
+    /// Create a `SystemTime` from a signed offset from the unix epoch.
+    ///
+    /// Returns `None` if the result is not representable.
+    fn from_unix_offset(offset: SignedDuration) -> Option<SystemTime>;
+
+    /// Whole seconds since the unix epoch, rounded towards negative infinity.
+    ///
+    /// The value saturates at the bounds of `i64`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::SystemTimeExt;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// assert_eq!((UNIX_EPOCH + Duration::from_millis(1_500)).unix_seconds(), 1);
+    /// assert_eq!((UNIX_EPOCH - Duration::from_millis(1_500)).unix_seconds(), -2);
+    /// ```
+    fn unix_seconds(&self) -> i64;
+
+    /// Whole milliseconds since the unix epoch, rounded towards negative infinity.
+    ///
+    /// The value saturates at the bounds of `i64`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::SystemTimeExt;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// assert_eq!((UNIX_EPOCH + Duration::from_micros(1_500)).unix_millis(), 1);
+    /// assert_eq!((UNIX_EPOCH - Duration::from_micros(1_500)).unix_millis(), -2);
+    /// ```
+    fn unix_millis(&self) -> i64;
+
+    /// Create a `SystemTime` from seconds since the unix epoch.
+    ///
+    /// Returns `None` if the result is not representable.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::SystemTimeExt;
+    /// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    ///
+    /// assert_eq!(
+    ///     SystemTime::from_unix_seconds_checked(-60),
+    ///     Some(UNIX_EPOCH - Duration::from_secs(60))
+    /// );
+    /// ```
+    fn from_unix_seconds_checked(secs: i64) -> Option<SystemTime>;
+
+    /// Create a `SystemTime` from milliseconds since the unix epoch.
+    ///
+    /// Returns `None` if the result is not representable.
+    fn from_unix_millis_checked(millis: i64) -> Option<SystemTime>;
+
+    /// Round down to the start of the day (midnight) in UTC.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::SystemTimeExt;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let t = UNIX_EPOCH + Duration::from_secs(86_400 + 3_600);
+    /// assert_eq!(t.truncate_to_utc_day(), UNIX_EPOCH + Duration::from_secs(86_400));
+    ///
+    /// let t = UNIX_EPOCH - Duration::from_secs(3_600);
+    /// assert_eq!(t.truncate_to_utc_day(), UNIX_EPOCH - Duration::from_secs(86_400));
+    /// ```
+    fn truncate_to_utc_day(&self) -> SystemTime;
+}
+
+impl SystemTimeExt for SystemTime {
+    fn unix_offset(&self) -> SignedDuration {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(d) => SignedDuration::positive(d),
+            Err(e) => SignedDuration::negative(e.duration()),
+        }
+    }
+
+    fn from_unix_offset(offset: SignedDuration) -> Option<SystemTime> {
+        if offset.is_negative() {
+            UNIX_EPOCH.checked_sub(offset.unsigned_abs())
+        } else {
+            UNIX_EPOCH.checked_add(offset.unsigned_abs())
+        }
+    }
+
+    fn unix_seconds(&self) -> i64 {
+        floor_div(self.unix_offset(), Duration::from_secs(1))
+    }
+
+    fn unix_millis(&self) -> i64 {
+        floor_div(self.unix_offset(), Duration::from_millis(1))
+    }
+
+    fn from_unix_seconds_checked(secs: i64) -> Option<SystemTime> {
+        extfn::checked_from_secs(secs.unsigned_abs())
+            .and_then(|d| Self::from_unix_offset(SignedDuration::from_parts(secs < 0, d)))
+    }
+
+    fn from_unix_millis_checked(millis: i64) -> Option<SystemTime> {
+        extfn::checked_from_millis(millis.unsigned_abs())
+            .and_then(|d| Self::from_unix_offset(SignedDuration::from_parts(millis < 0, d)))
+    }
+
+    fn truncate_to_utc_day(&self) -> SystemTime {
+        let days = floor_div(self.unix_offset(), Duration::from_secs(SECS_PER_DAY)) as i128;
+        let secs = days * SECS_PER_DAY as i128;
+        // the start of the day is never earlier than the representable range in practice,
+        // fall back to the original value if it is
+        i64::try_from(secs)
+            .ok()
+            .and_then(Self::from_unix_seconds_checked)
+            .unwrap_or(*self)
+    }
+}
+
+/// `offset / unit`, rounded towards negative infinity and saturated to `i64`.
+fn floor_div(offset: SignedDuration, unit: Duration) -> i64 {
+    let nanos = offset.as_nanos();
+    let unit = unit.as_nanos() as i128;
+    let quotient = nanos.div_euclid(unit);
+    quotient.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
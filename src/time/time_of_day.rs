@@ -0,0 +1,196 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use super::parse::{ParseDurationError, ParseDurationErrorKind};
+use super::{SystemTimeExt, NANOS_PER_SEC, SECS_PER_DAY, SECS_PER_HOUR, SECS_PER_MINUTE};
+
+const NANOS_PER_DAY: u64 = SECS_PER_DAY * NANOS_PER_SEC as u64;
+
+/// A time of day, without a date or a time zone.
+///
+/// Arithmetic wraps around midnight.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::time::{DurationNumExt, TimeOfDay};
+///
+/// let t = TimeOfDay::new(23, 30, 0).unwrap();
+/// assert_eq!(t.wrapping_add(1.hours()), TimeOfDay::new(0, 30, 0).unwrap());
+/// assert_eq!(t.duration_until(TimeOfDay::MIDNIGHT), 30.minutes());
+///
+/// assert_eq!("08:15".parse::<TimeOfDay>().unwrap(), TimeOfDay::new(8, 15, 0).unwrap());
+/// assert_eq!(t.to_string(), "23:30:00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TimeOfDay {
+    /// Nanoseconds since midnight, always less than a day.
+    nanos: u64,
+}
+
+impl TimeOfDay {
+    /// The start of the day, `00:00:00`.
+    pub const MIDNIGHT: TimeOfDay = TimeOfDay { nanos: 0 };
+
+    /// Create a time of day from hours, minutes and seconds.
+    ///
+    /// Returns `None` if a field is out of range.
+    pub fn new(hour: u32, minute: u32, second: u32) -> Option<TimeOfDay> {
+        Self::with_nanos(hour, minute, second, 0)
+    }
+
+    /// Create a time of day from hours, minutes, seconds and nanoseconds.
+    ///
+    /// Returns `None` if a field is out of range.
+    pub fn with_nanos(hour: u32, minute: u32, second: u32, nanos: u32) -> Option<TimeOfDay> {
+        if hour >= 24 || minute >= 60 || second >= 60 || nanos >= NANOS_PER_SEC {
+            return None;
+        }
+        let secs = hour as u64 * SECS_PER_HOUR + minute as u64 * SECS_PER_MINUTE + second as u64;
+        Some(TimeOfDay {
+            nanos: secs * NANOS_PER_SEC as u64 + nanos as u64,
+        })
+    }
+
+    /// Create a time of day from the time elapsed since midnight.
+    ///
+    /// Returns `None` if `duration` is a day or longer.
+    pub fn from_duration_since_midnight(duration: Duration) -> Option<TimeOfDay> {
+        let nanos = u64::try_from(duration.as_nanos()).ok()?;
+        (nanos < NANOS_PER_DAY).then_some(TimeOfDay { nanos })
+    }
+
+    /// The time of day of a `SystemTime`, in UTC.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::TimeOfDay;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let t = UNIX_EPOCH + Duration::from_secs(86_400 + 3_600);
+    /// assert_eq!(TimeOfDay::of_system_time_utc(t), TimeOfDay::new(1, 0, 0).unwrap());
+    /// ```
+    pub fn of_system_time_utc(time: SystemTime) -> TimeOfDay {
+        let since_midnight = time
+            .duration_since(time.truncate_to_utc_day())
+            .unwrap_or_default();
+        // the offset from the start of the day is less than a day
+        TimeOfDay::from_duration_since_midnight(since_midnight).unwrap_or_default()
+    }
+
+    /// The time elapsed since midnight.
+    pub fn since_midnight(&self) -> Duration {
+        Duration::from_nanos(self.nanos)
+    }
+
+    /// The hour, in `0..24`.
+    pub fn hour(&self) -> u32 {
+        (self.nanos / NANOS_PER_SEC as u64 / SECS_PER_HOUR) as u32
+    }
+
+    /// The minute, in `0..60`.
+    pub fn minute(&self) -> u32 {
+        (self.nanos / NANOS_PER_SEC as u64 / SECS_PER_MINUTE % 60) as u32
+    }
+
+    /// The second, in `0..60`.
+    pub fn second(&self) -> u32 {
+        (self.nanos / NANOS_PER_SEC as u64 % SECS_PER_MINUTE) as u32
+    }
+
+    /// The fraction of the second, in nanoseconds.
+    pub fn nanosecond(&self) -> u32 {
+        (self.nanos % NANOS_PER_SEC as u64) as u32
+    }
+
+    /// Add a duration, wrapping around midnight.
+    pub fn wrapping_add(&self, duration: Duration) -> TimeOfDay {
+        let add = (duration.as_nanos() % NANOS_PER_DAY as u128) as u64;
+        TimeOfDay {
+            nanos: (self.nanos + add) % NANOS_PER_DAY,
+        }
+    }
+
+    /// Subtract a duration, wrapping around midnight.
+    pub fn wrapping_sub(&self, duration: Duration) -> TimeOfDay {
+        let sub = (duration.as_nanos() % NANOS_PER_DAY as u128) as u64;
+        TimeOfDay {
+            nanos: (self.nanos + NANOS_PER_DAY - sub) % NANOS_PER_DAY,
+        }
+    }
+
+    /// The time from `self` until the next occurrence of `other`,
+    /// which is zero if they are equal.
+    pub fn duration_until(&self, other: TimeOfDay) -> Duration {
+        Duration::from_nanos((other.nanos + NANOS_PER_DAY - self.nanos) % NANOS_PER_DAY)
+    }
+}
+
+impl fmt::Display for TimeOfDay {
+    /// Formats as `HH:MM:SS`, followed by the fraction of the second without trailing zeros.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.hour(),
+            self.minute(),
+            self.second()
+        )?;
+        if self.nanosecond() != 0 {
+            let fraction = format!("{:09}", self.nanosecond());
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TimeOfDay {
+    type Err = ParseDurationError;
+
+    /// Parse `HH:MM`, `HH:MM:SS` or `HH:MM:SS.fff` (with up to 9 fractional digits).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |kind, position| Err(ParseDurationError::new(kind, position));
+
+        let mut fields = [0u32; 3];
+        let mut nanos = 0;
+        let mut count = 0;
+        let mut position = 0;
+        for (i, field) in s.split(':').enumerate() {
+            if i == fields.len() {
+                return error(ParseDurationErrorKind::InvalidFormat, position - 1);
+            }
+            let (int, fraction) = match field.split_once('.') {
+                Some((int, fraction)) if i == 2 => (int, Some(fraction)),
+                _ => (field, None),
+            };
+            if int.len() != 2 || !int.bytes().all(|c| c.is_ascii_digit()) {
+                return error(ParseDurationErrorKind::InvalidNumber, position);
+            }
+            fields[i] = int.parse().unwrap_or_default();
+            if let Some(fraction) = fraction {
+                let valid = (1..=9).contains(&fraction.len())
+                    && fraction.bytes().all(|c| c.is_ascii_digit());
+                if !valid {
+                    return error(ParseDurationErrorKind::InvalidNumber, position + 3);
+                }
+                nanos = format!("{:0<9}", fraction).parse().unwrap_or_default();
+            }
+            count += 1;
+            position += field.len() + 1;
+        }
+        if count < 2 {
+            return error(ParseDurationErrorKind::InvalidFormat, s.len());
+        }
+
+        // every field is two digits followed by a colon
+        let limits = [24, 60, 60];
+        if let Some(i) = (0..fields.len()).find(|&i| fields[i] >= limits[i]) {
+            return error(ParseDurationErrorKind::FieldOutOfRange, i * 3);
+        }
+        let [hour, minute, second] = fields;
+        TimeOfDay::with_nanos(hour, minute, second, nanos)
+            .map_or_else(|| error(ParseDurationErrorKind::FieldOutOfRange, 0), Ok)
+    }
+}
@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use super::{
+    NANOS_PER_MICRO, NANOS_PER_MILLI, NANOS_PER_SEC, SECS_PER_DAY, SECS_PER_HOUR, SECS_PER_MINUTE,
+    SECS_PER_WEEK,
+};
+
+/// Units of time that a [`std::time::Duration`] can be expressed in.
+///
+/// The variants are ordered from the smallest to the largest unit,
+/// so `TimeUnit::Second < TimeUnit::Minute` holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimeUnit {
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+}
+
+impl TimeUnit {
+    /// All units, from the smallest to the largest.
+    pub const ALL: [TimeUnit; 8] = [
+        TimeUnit::Nanosecond,
+        TimeUnit::Microsecond,
+        TimeUnit::Millisecond,
+        TimeUnit::Second,
+        TimeUnit::Minute,
+        TimeUnit::Hour,
+        TimeUnit::Day,
+        TimeUnit::Week,
+    ];
+
+    /// The length of the unit in nanoseconds.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::TimeUnit;
+    ///
+    /// assert_eq!(TimeUnit::Millisecond.as_nanos(), 1_000_000);
+    /// assert_eq!(TimeUnit::Minute.as_nanos(), 60_000_000_000);
+    /// ```
+    pub const fn as_nanos(self) -> u128 {
+        match self {
+            TimeUnit::Nanosecond => 1,
+            TimeUnit::Microsecond => NANOS_PER_MICRO as u128,
+            TimeUnit::Millisecond => NANOS_PER_MILLI as u128,
+            TimeUnit::Second => NANOS_PER_SEC as u128,
+            TimeUnit::Minute => SECS_PER_MINUTE as u128 * NANOS_PER_SEC as u128,
+            TimeUnit::Hour => SECS_PER_HOUR as u128 * NANOS_PER_SEC as u128,
+            TimeUnit::Day => SECS_PER_DAY as u128 * NANOS_PER_SEC as u128,
+            TimeUnit::Week => SECS_PER_WEEK as u128 * NANOS_PER_SEC as u128,
+        }
+    }
+
+    /// The length of the unit as a `Duration`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use rs_std_ext::time::TimeUnit;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(TimeUnit::Hour.as_duration(), Duration::from_secs(3600));
+    /// ```
+    pub const fn as_duration(self) -> Duration {
+        match self {
+            TimeUnit::Nanosecond => Duration::from_nanos(1),
+            TimeUnit::Microsecond => Duration::from_micros(1),
+            TimeUnit::Millisecond => Duration::from_millis(1),
+            TimeUnit::Second => Duration::from_secs(1),
+            TimeUnit::Minute => Duration::from_secs(SECS_PER_MINUTE),
+            TimeUnit::Hour => Duration::from_secs(SECS_PER_HOUR),
+            TimeUnit::Day => Duration::from_secs(SECS_PER_DAY),
+            TimeUnit::Week => Duration::from_secs(SECS_PER_WEEK),
+        }
+    }
+
+    /// The abbreviated name of the unit, e.g. `ms` or `h`.
+    pub const fn short_name(self) -> &'static str {
+        match self {
+            TimeUnit::Nanosecond => "ns",
+            TimeUnit::Microsecond => "µs",
+            TimeUnit::Millisecond => "ms",
+            TimeUnit::Second => "s",
+            TimeUnit::Minute => "m",
+            TimeUnit::Hour => "h",
+            TimeUnit::Day => "d",
+            TimeUnit::Week => "w",
+        }
+    }
+
+    /// The full (singular) name of the unit, e.g. `millisecond` or `hour`.
+    pub const fn long_name(self) -> &'static str {
+        match self {
+            TimeUnit::Nanosecond => "nanosecond",
+            TimeUnit::Microsecond => "microsecond",
+            TimeUnit::Millisecond => "millisecond",
+            TimeUnit::Second => "second",
+            TimeUnit::Minute => "minute",
+            TimeUnit::Hour => "hour",
+            TimeUnit::Day => "day",
+            TimeUnit::Week => "week",
+        }
+    }
+
+    pub(crate) const fn index(self) -> usize {
+        self as usize
+    }
+}
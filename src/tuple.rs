@@ -1,12 +1,18 @@
-//! Extension for typle types.
+//! Extension for tuple types.
 //!
-//! All traits in this module are implemented with tuple no longer than 10.
+//! The implementations are generated by the build script,
+//! the longest supported tuple depends on the enabled features:
 //!
-//! By default, the module only expose implementation for tuple no longer than 5.
-//! You can use longer impls by enabling the `long-tuple-impl` feature.
+//! | Feature           | Longest tuple |
+//! |-------------------|---------------|
+//! | (default)         | 5             |
+//! | `long-tuple-impl` | 10            |
+//! | `tuple-16`        | 16            |
+//! | `tuple-32`        | 32            |
 //!
 //! Note that the [`TupleConcat`] trait is only implemented for those types that
-//! returns a tuple shorter than 10.
+//! returns a tuple no longer than 10, or the longest tuple if that is longer.
+//! The [`TupleSwap`] trait is only implemented for tuples of up to 16 elements.
 
 /// Zip a tuple with another single value.
 ///
@@ -131,8 +137,9 @@ pub trait TupleRemove {
 ///
 /// **Note:**
 /// This trait only has implementations for operations that return tuples of
-/// length 5 / 10 (`long-tuple-impl` feature) or less.
-/// That means that `(A, B, C, D, E, F, G)` cannot be concated with `(H, I, J, K, L)`.
+/// length 10 or less, or up to the longest supported tuple (see the [module docs](self)).
+/// That means that without the `tuple-16` feature,
+/// `(A, B, C, D, E, F, G)` cannot be concated with `(H, I, J, K, L)`.
 ///
 /// ## Example
 ///
@@ -151,11 +158,614 @@ pub trait TupleConcat<T> {
     fn concat(self, other: T) -> Self::Output;
 }
 
+/// Apply a closure to every element of a tuple.
+///
+/// The closures are given as a tuple of the same length,
+/// the closure at each position transforms the element at that position.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleMap;
+///
+/// let x = (10u8, "foo", 2.5f64);
+/// let y = x.map((|a: u8| a * 2, str::len, |c: f64| c.floor()));
+///
+/// assert_eq!(y, (20u8, 3usize, 2.0f64));
+/// ```
+pub trait TupleMap<F> {
+    type Output;
+
+    /// Apply the closure at each position to the element at that position.
+    fn map(self, f: F) -> Self::Output;
+}
+
+/// Transform a single element of a tuple.
+///
+/// This is internally used behind the [`TupleMapNth`] trait for convenience.
+///
+/// However, if something goes wrong with the trait solver,
+/// this trait can also be used explicitly.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleMapNthExact;
+///
+/// let x = (10u8, 'a');
+/// let y = <(u8, char) as TupleMapNthExact<1, _>>::map_nth(x, |c: char| c.is_alphabetic());
+///
+/// assert_eq!(y, (10u8, true));
+/// ```
+pub trait TupleMapNthExact<const POS: usize, F> {
+    type Output;
+
+    fn map_nth(self, f: F) -> Self::Output;
+}
+
+/// Transform a single element of a tuple.
+///
+/// Unlike `TupleMapNthExact`, this trait moves the `const POS` to a method,
+/// so it can be used with the help of type derivation
+/// without explicitly declaring the underlying trait.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleMapNth;
+///
+/// let x = (10u8, 'a', -5i32);
+/// let y = x.map_nth::<2>(|v: i32| v.to_string());
+///
+/// assert_eq!(y, (10u8, 'a', "-5".to_string()));
+/// ```
+pub trait TupleMapNth<F> {
+    fn map_nth<const POS: usize>(self, f: F) -> Self::Output
+    where
+        Self: TupleMapNthExact<POS, F> + Sized,
+    {
+        <Self as TupleMapNthExact<POS, F>>::map_nth(self, f)
+    }
+}
+
+/// Convert a homogeneous tuple into an array.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleToArray;
+///
+/// let x = (1, 2, 3);
+/// let y: [i32; 3] = x.into_array();
+///
+/// assert_eq!(y, [1, 2, 3]);
+/// ```
+pub trait TupleToArray<T, const N: usize> {
+    /// Convert into an array, keeping the order of the elements.
+    fn into_array(self) -> [T; N];
+}
+
+/// Convert an array into a homogeneous tuple.
+///
+/// This is the reverse of [`TupleToArray`].
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::ArrayToTuple;
+///
+/// let x = [1, 2, 3];
+/// let y = x.into_tuple();
+///
+/// assert_eq!(y, (1, 2, 3));
+/// ```
+pub trait ArrayToTuple {
+    type Output;
+
+    /// Convert into a tuple, keeping the order of the elements.
+    fn into_tuple(self) -> Self::Output;
+}
+
+/// Iterate over the elements of a homogeneous tuple.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleIter;
+///
+/// let mut x = (1, 2, 3);
+/// for v in x.iter_mut() {
+///     *v *= 10;
+/// }
+///
+/// assert_eq!(x.iter().sum::<i32>(), 60);
+/// assert_eq!(x.into_iter().collect::<Vec<_>>(), vec![10, 20, 30]);
+/// ```
+pub trait TupleIter<T, const N: usize>: TupleToArray<T, N> {
+    /// Iterate over references to the elements.
+    fn iter(&self) -> std::array::IntoIter<&T, N>;
+
+    /// Iterate over mutable references to the elements.
+    fn iter_mut(&mut self) -> std::array::IntoIter<&mut T, N>;
+
+    /// Iterate over the elements by value.
+    fn into_iter(self) -> std::array::IntoIter<T, N>
+    where
+        Self: Sized,
+    {
+        self.into_array().into_iter()
+    }
+}
+
+/// Split a tuple into two tuples.
+///
+/// This is internally used behind the [`TupleSplit`] trait for convenience.
+///
+/// However, if something goes wrong with the trait solver,
+/// this trait can also be used explicitly.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleSplitExact;
+///
+/// let x = (10u8, 'a', -5i32);
+/// let (l, r) = <(u8, char, i32) as TupleSplitExact<1>>::split_at(x);
+///
+/// assert_eq!(l, (10u8,));
+/// assert_eq!(r, ('a', -5i32));
+/// ```
+pub trait TupleSplitExact<const POS: usize> {
+    type Left;
+    type Right;
+
+    fn split_at(self) -> (Self::Left, Self::Right);
+}
+
+/// Split a tuple into two tuples.
+///
+/// The left tuple contains the first `POS` elements, the right tuple the rest.
+/// Either of them can be `()`.
+///
+/// This is the inverse of [`TupleConcat`].
+///
+/// Unlike `TupleSplitExact`, this trait moves the `const POS` to a method,
+/// so it can be used with the help of type derivation
+/// without explicitly declaring the underlying trait.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleSplit;
+///
+/// let x = (10u8, 'a', -5i32);
+///
+/// assert_eq!(x.split_at::<2>(), ((10u8, 'a'), (-5i32,)));
+/// assert_eq!(x.split_at::<0>(), ((), (10u8, 'a', -5i32)));
+/// ```
+pub trait TupleSplit {
+    fn split_at<const POS: usize>(self) -> (Self::Left, Self::Right)
+    where
+        Self: TupleSplitExact<POS> + Sized,
+    {
+        <Self as TupleSplitExact<POS>>::split_at(self)
+    }
+}
+
+/// Take the first or the last element of a tuple.
+///
+/// The popping methods are the inverse of [`TupleInsert`] at the ends of the tuple,
+/// and `pop_back` is the inverse of [`TupleZip`].
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TuplePop;
+///
+/// let x = (10u8, 'a', -5i32);
+///
+/// assert_eq!(x.first(), &10u8);
+/// assert_eq!(x.last(), &-5i32);
+/// assert_eq!(x.pop_front(), (10u8, ('a', -5i32)));
+/// assert_eq!(x.pop_back(), (-5i32, (10u8, 'a')));
+/// ```
+pub trait TuplePop {
+    type First;
+    type Last;
+    /// The tuple without its first element.
+    type Tail;
+    /// The tuple without its last element.
+    type Init;
+
+    /// Remove the first element, returning it and the rest of the tuple.
+    fn pop_front(self) -> (Self::First, Self::Tail);
+
+    /// Remove the last element, returning it and the rest of the tuple.
+    fn pop_back(self) -> (Self::Last, Self::Init);
+
+    /// A reference to the first element.
+    fn first(&self) -> &Self::First;
+
+    /// A reference to the last element.
+    fn last(&self) -> &Self::Last;
+}
+
+/// Reverse the order of the elements of a tuple.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleReverse;
+///
+/// let x = (10u8, 'a', -5i32);
+///
+/// assert_eq!(x.reverse(), (-5i32, 'a', 10u8));
+/// ```
+pub trait TupleReverse {
+    type Output;
+
+    fn reverse(self) -> Self::Output;
+}
+
+/// Rotate a tuple to the left.
+///
+/// This is internally used behind the [`TupleRotate`] trait for convenience.
+///
+/// However, if something goes wrong with the trait solver,
+/// this trait can also be used explicitly.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleRotateLeftExact;
+///
+/// let x = (10u8, 'a', -5i32);
+/// let y = <(u8, char, i32) as TupleRotateLeftExact<1>>::rotate_left(x);
+///
+/// assert_eq!(y, ('a', -5i32, 10u8));
+/// ```
+pub trait TupleRotateLeftExact<const MID: usize> {
+    type Output;
+
+    fn rotate_left(self) -> Self::Output;
+}
+
+/// Rotate a tuple to the right.
+///
+/// This is internally used behind the [`TupleRotate`] trait for convenience.
+///
+/// However, if something goes wrong with the trait solver,
+/// this trait can also be used explicitly.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleRotateRightExact;
+///
+/// let x = (10u8, 'a', -5i32);
+/// let y = <(u8, char, i32) as TupleRotateRightExact<1>>::rotate_right(x);
+///
+/// assert_eq!(y, (-5i32, 10u8, 'a'));
+/// ```
+pub trait TupleRotateRightExact<const K: usize> {
+    type Output;
+
+    fn rotate_right(self) -> Self::Output;
+}
+
+/// Rotate a tuple.
+///
+/// This works like [`slice::rotate_left`] and [`slice::rotate_right`],
+/// the amount can be anything from zero to the length of the tuple.
+///
+/// Unlike `TupleRotateLeftExact` and `TupleRotateRightExact`,
+/// this trait moves the const parameter to a method,
+/// so it can be used with the help of type derivation
+/// without explicitly declaring the underlying trait.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleRotate;
+///
+/// let x = (10u8, 'a', -5i32);
+///
+/// assert_eq!(x.rotate_left::<2>(), (-5i32, 10u8, 'a'));
+/// assert_eq!(x.rotate_right::<2>(), ('a', -5i32, 10u8));
+/// ```
+pub trait TupleRotate {
+    fn rotate_left<const MID: usize>(self) -> <Self as TupleRotateLeftExact<MID>>::Output
+    where
+        Self: TupleRotateLeftExact<MID> + Sized,
+    {
+        <Self as TupleRotateLeftExact<MID>>::rotate_left(self)
+    }
+
+    fn rotate_right<const K: usize>(self) -> <Self as TupleRotateRightExact<K>>::Output
+    where
+        Self: TupleRotateRightExact<K> + Sized,
+    {
+        <Self as TupleRotateRightExact<K>>::rotate_right(self)
+    }
+}
+
+/// Swap two elements of a tuple.
+///
+/// This trait is only implemented for tuples of up to 16 elements,
+/// even with the `tuple-32` feature (see the [module docs](self)).
+///
+/// This is internally used behind the [`TupleSwap`] trait for convenience.
+///
+/// However, if something goes wrong with the trait solver,
+/// this trait can also be used explicitly.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleSwapExact;
+///
+/// let x = (10u8, 'a', -5i32);
+/// let y = <(u8, char, i32) as TupleSwapExact<0, 2>>::swap(x);
+///
+/// assert_eq!(y, (-5i32, 'a', 10u8));
+/// ```
+pub trait TupleSwapExact<const I: usize, const J: usize> {
+    type Output;
+
+    fn swap(self) -> Self::Output;
+}
+
+/// Swap two elements of a tuple.
+///
+/// Unlike `TupleSwapExact`, this trait moves the const parameters to a method,
+/// so it can be used with the help of type derivation
+/// without explicitly declaring the underlying trait.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleSwap;
+///
+/// let x = (10u8, 'a', -5i32);
+///
+/// assert_eq!(x.swap::<1, 2>(), (10u8, -5i32, 'a'));
+/// ```
+pub trait TupleSwap {
+    fn swap<const I: usize, const J: usize>(self) -> Self::Output
+    where
+        Self: TupleSwapExact<I, J> + Sized,
+    {
+        <Self as TupleSwapExact<I, J>>::swap(self)
+    }
+}
+
+/// Access a single element of a tuple by its position.
+///
+/// This is internally used behind the [`TupleGet`] trait for convenience.
+///
+/// However, if something goes wrong with the trait solver,
+/// this trait can also be used explicitly.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleGetExact;
+///
+/// let x = (10u8, 'a', -5i32);
+/// let y = <(u8, char, i32) as TupleGetExact<1>>::get(&x);
+///
+/// assert_eq!(y, &'a');
+/// ```
+pub trait TupleGetExact<const POS: usize> {
+    type Output;
+    /// The tuple with the element at `POS` replaced by a `T`.
+    type Replaced<T>;
+
+    fn get(&self) -> &Self::Output;
+
+    fn get_mut(&mut self) -> &mut Self::Output;
+
+    fn replace<T>(self, val: T) -> (Self::Replaced<T>, Self::Output);
+}
+
+/// Access a single element of a tuple by its position.
+///
+/// To replace an element, possibly with a value of another type, see [`TupleReplace`].
+///
+/// Unlike `TupleGetExact`, this trait moves the `const POS` to a method,
+/// so it can be used with the help of type derivation
+/// without explicitly declaring the underlying trait.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleGet;
+///
+/// let mut x = (10u8, 'a', -5i32);
+/// assert_eq!(x.get::<0>(), &10u8);
+///
+/// *x.get_mut::<2>() += 10;
+/// assert_eq!(x, (10u8, 'a', 5i32));
+/// ```
+pub trait TupleGet {
+    fn get<const POS: usize>(&self) -> &Self::Output
+    where
+        Self: TupleGetExact<POS>,
+    {
+        <Self as TupleGetExact<POS>>::get(self)
+    }
+
+    fn get_mut<const POS: usize>(&mut self) -> &mut Self::Output
+    where
+        Self: TupleGetExact<POS>,
+    {
+        <Self as TupleGetExact<POS>>::get_mut(self)
+    }
+}
+
+/// Replace a single element of a tuple by its position.
+///
+/// This returns the new tuple and the old value,
+/// the new value can have a different type than the old one.
+///
+/// Unlike `TupleGetExact`, this trait moves the `const POS` to a method,
+/// so it can be used with the help of type derivation
+/// without explicitly declaring the underlying trait.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleReplace;
+///
+/// let x = (10u8, 'a', -5i32);
+/// let (y, old) = x.replace::<1>("foo");
+///
+/// assert_eq!(y, (10u8, "foo", -5i32));
+/// assert_eq!(old, 'a');
+/// ```
+pub trait TupleReplace<T> {
+    fn replace<const POS: usize>(self, val: T) -> (Self::Replaced<T>, Self::Output)
+    where
+        Self: TupleGetExact<POS> + Sized,
+    {
+        <Self as TupleGetExact<POS>>::replace(self, val)
+    }
+}
+
+/// Call a function with the elements of a tuple as its arguments.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleApply;
+///
+/// let x = (10u8, "foo", true);
+/// let y = x.apply(|a: u8, b: &str, c: bool| format!("{a} {b} {c}"));
+///
+/// assert_eq!(y, "10 foo true");
+/// ```
+pub trait TupleApply<F> {
+    type Output;
+
+    fn apply(self, f: F) -> Self::Output;
+}
+
+/// Call a function with references to the elements of a tuple as its arguments.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleApplyRef;
+///
+/// let x = (String::from("foo"), vec![1, 2]);
+/// let y = x.apply_ref(|a: &String, b: &Vec<i32>| a.len() + b.len());
+///
+/// assert_eq!(y, 5);
+/// ```
+pub trait TupleApplyRef<F> {
+    type Output;
+
+    fn apply_ref(&self, f: F) -> Self::Output;
+}
+
+/// Call a function with mutable references to the elements of a tuple as its arguments.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleApplyMut;
+///
+/// let mut x = (String::from("foo"), 10);
+/// x.apply_mut(|a: &mut String, b: &mut i32| {
+///     a.push('!');
+///     *b += 1;
+/// });
+///
+/// assert_eq!(x, (String::from("foo!"), 11));
+/// ```
+pub trait TupleApplyMut<F> {
+    type Output;
+
+    fn apply_mut(&mut self, f: F) -> Self::Output;
+}
+
+/// Extension for functions, converting between separate arguments and a tuple of arguments.
+///
+/// This is the inverse of [`TupleApply`].
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::FnExt;
+///
+/// let add = |a: i32, b: i32| a + b;
+/// let sums: Vec<_> = vec![(1, 2), (3, 4)].into_iter().map(add.tupled()).collect();
+///
+/// assert_eq!(sums, [3, 7]);
+/// ```
+pub trait FnExt<Args> {
+    type Output;
+
+    /// Convert a function taking several arguments into a function taking one tuple.
+    fn tupled(self) -> impl Fn(Args) -> Self::Output;
+}
+
+/// Transpose a tuple of `Option`s or `Result`s into an `Option` or `Result` of a tuple.
+///
+/// For `Result`s, all errors must have the same type, and the first error is returned.
+/// To keep every error, see [`TupleTransposeAll`].
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleTranspose;
+///
+/// assert_eq!((Some(1), Some("a")).transpose(), Some((1, "a")));
+/// assert_eq!((Some(1), None::<&str>).transpose(), None);
+///
+/// let x: (Result<i32, &str>, Result<char, &str>) = (Ok(1), Ok('c'));
+/// assert_eq!(x.transpose(), Ok((1, 'c')));
+///
+/// let y: (Result<i32, &str>, Result<char, &str>) = (Err("foo"), Err("bar"));
+/// assert_eq!(y.transpose(), Err("foo"));
+/// ```
+pub trait TupleTranspose {
+    type Output;
+
+    fn transpose(self) -> Self::Output;
+}
+
+/// Transpose a tuple of `Result`s into a `Result` of a tuple, collecting every error.
+///
+/// The error is a tuple holding the error of each position, if any.
+/// Unlike [`TupleTranspose`], the errors can have different types.
+///
+/// ## Example
+///
+/// ```rust
+/// use rs_std_ext::tuple::TupleTransposeAll;
+///
+/// let x: (Result<i32, &str>, Result<char, u8>) = (Ok(1), Ok('c'));
+/// assert_eq!(x.transpose_all(), Ok((1, 'c')));
+///
+/// let y: (Result<i32, &str>, Result<char, u8>, Result<bool, ()>) = (Err("foo"), Ok('c'), Err(()));
+/// assert_eq!(y.transpose_all(), Err((Some("foo"), None, Some(()))));
+/// ```
+pub trait TupleTransposeAll {
+    type Output;
+
+    fn transpose_all(self) -> Self::Output;
+}
+
 mod __generated {
     use paste::paste;
 
     use super::{
-        TupleConcat, TupleInsert, TupleInsertExact, TupleRemove, TupleRemoveExact, TupleZip,
+        ArrayToTuple, FnExt, TupleApply, TupleApplyMut, TupleApplyRef, TupleConcat, TupleGet,
+        TupleGetExact, TupleInsert, TupleInsertExact, TupleIter, TupleMap, TupleMapNth,
+        TupleMapNthExact, TuplePop, TupleRemove, TupleRemoveExact, TupleReplace, TupleReverse,
+        TupleRotate, TupleRotateLeftExact, TupleRotateRightExact, TupleSplit, TupleSplitExact,
+        TupleSwap, TupleSwapExact, TupleToArray, TupleTranspose, TupleTransposeAll, TupleZip,
     };
 
     macro_rules! __impl_tuple_zip {
@@ -202,15 +812,15 @@ mod __generated {
     }
 
     macro_rules! __impl_tuple_remove_exact {
-        ($($ph:ident),+ at $index:expr => $($res:ident),+) => {
+        ($($ph:ident),+ at $index:expr => $($res:ident),*) => {
             paste! {
                 impl<$($ph),+> TupleRemoveExact<$index> for ($($ph,)+) {
-                    type Output = ($($res,)+);
+                    type Output = ($($res,)*);
 
-                    #[allow(unused_variables)]
+                    #[allow(unused_variables, clippy::unused_unit)]
                     fn remove(self) -> Self::Output {
                         let ($([< $ph:lower >],)+) = self;
-                        ($([< $res:lower >],)+)
+                        ($([< $res:lower >],)*)
                     }
                 }
             }
@@ -233,22 +843,351 @@ mod __generated {
         };
     }
 
-    include!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/src/tuple_short_impl.rs"
-    ));
+    macro_rules! __impl_tuple_map {
+        ($($ph:ident),+) => {
+            paste! {
+                impl<$($ph),+, $([< F $ph >], [< R $ph >]),+> TupleMap<($([< F $ph >],)+)>
+                    for ($($ph,)+)
+                where
+                    $([< F $ph >]: FnOnce($ph) -> [< R $ph >]),+
+                {
+                    type Output = ($([< R $ph >],)+);
+
+                    fn map(self, mappers: ($([< F $ph >],)+)) -> Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        let ($([< f_ $ph:lower >],)+) = mappers;
+                        ($([< f_ $ph:lower >]([< $ph:lower >]),)+)
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! __impl_tuple_map_nth {
+        ($($ph:ident),+ | $tar:ident) => {
+            impl<$($ph),+, $tar> TupleMapNth<$tar> for ($($ph,)+) {}
+        };
+    }
+
+    macro_rules! __impl_tuple_map_nth_exact {
+        ($($ph:ident),+ | $tar:ident -> $out:ident at $index:expr => $($res:ident),+) => {
+            paste! {
+                impl<$($ph),+, M, $out> TupleMapNthExact<$index, M> for ($($ph,)+)
+                where
+                    M: FnOnce($tar) -> $out,
+                {
+                    type Output = ($($res,)+);
+
+                    fn map_nth(self, mapper: M) -> Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        let [< $out:lower >] = mapper([< $tar:lower >]);
+                        ($([< $res:lower >],)+)
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! __homogeneous {
+        ($ph:ident => $ty:ty) => {
+            $ty
+        };
+    }
+
+    macro_rules! __impl_tuple_array {
+        ($($ph:ident),+ => $len:expr) => {
+            paste! {
+                impl<T> TupleToArray<T, $len> for ($(__homogeneous!($ph => T),)+) {
+                    fn into_array(self) -> [T; $len] {
+                        let ($([< $ph:lower >],)+) = self;
+                        [$([< $ph:lower >]),+]
+                    }
+                }
+
+                impl<T> ArrayToTuple for [T; $len] {
+                    type Output = ($(__homogeneous!($ph => T),)+);
+
+                    fn into_tuple(self) -> Self::Output {
+                        let [$([< $ph:lower >]),+] = self;
+                        ($([< $ph:lower >],)+)
+                    }
+                }
+
+                impl<T> TupleIter<T, $len> for ($(__homogeneous!($ph => T),)+) {
+                    fn iter(&self) -> std::array::IntoIter<&T, $len> {
+                        let ($([< $ph:lower >],)+) = self;
+                        [$([< $ph:lower >]),+].into_iter()
+                    }
+
+                    fn iter_mut(&mut self) -> std::array::IntoIter<&mut T, $len> {
+                        let ($([< $ph:lower >],)+) = self;
+                        [$([< $ph:lower >]),+].into_iter()
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! __impl_tuple_split {
+        ($($ph:ident),+) => {
+            impl<$($ph),+> TupleSplit for ($($ph,)+) {}
+        };
+    }
+
+    macro_rules! __impl_tuple_split_exact {
+        ($($ph:ident),+ at $index:expr => $($l:ident),* | $($r:ident),*) => {
+            paste! {
+                impl<$($ph),+> TupleSplitExact<$index> for ($($ph,)+) {
+                    type Left = ($($l,)*);
+                    type Right = ($($r,)*);
+
+                    fn split_at(self) -> (Self::Left, Self::Right) {
+                        let ($([< $ph:lower >],)+) = self;
+                        (($([< $l:lower >],)*), ($([< $r:lower >],)*))
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! __impl_tuple_pop {
+        ($($ph:ident),+ | $first:ident; $($tail:ident),* | $($init:ident),*; $last:ident) => {
+            paste! {
+                impl<$($ph),+> TuplePop for ($($ph,)+) {
+                    type First = $first;
+                    type Last = $last;
+                    type Tail = ($($tail,)*);
+                    type Init = ($($init,)*);
+
+                    fn pop_front(self) -> (Self::First, Self::Tail) {
+                        let ($([< $ph:lower >],)+) = self;
+                        ([< $first:lower >], ($([< $tail:lower >],)*))
+                    }
+
+                    fn pop_back(self) -> (Self::Last, Self::Init) {
+                        let ($([< $ph:lower >],)+) = self;
+                        ([< $last:lower >], ($([< $init:lower >],)*))
+                    }
+
+                    #[allow(unused_variables)]
+                    fn first(&self) -> &Self::First {
+                        let ($([< $ph:lower >],)+) = self;
+                        [< $first:lower >]
+                    }
+
+                    #[allow(unused_variables)]
+                    fn last(&self) -> &Self::Last {
+                        let ($([< $ph:lower >],)+) = self;
+                        [< $last:lower >]
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! __impl_tuple_reverse {
+        ($($ph:ident),+ => $($res:ident),+) => {
+            paste! {
+                impl<$($ph),+> TupleReverse for ($($ph,)+) {
+                    type Output = ($($res,)+);
+
+                    fn reverse(self) -> Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        ($([< $res:lower >],)+)
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! __impl_tuple_rotate {
+        ($($ph:ident),+) => {
+            impl<$($ph),+> TupleRotate for ($($ph,)+) {}
+        };
+    }
+
+    macro_rules! __impl_tuple_rotate_left {
+        ($($ph:ident),+ at $index:expr => $($res:ident),+) => {
+            paste! {
+                impl<$($ph),+> TupleRotateLeftExact<$index> for ($($ph,)+) {
+                    type Output = ($($res,)+);
+
+                    fn rotate_left(self) -> Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        ($([< $res:lower >],)+)
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! __impl_tuple_rotate_right {
+        ($($ph:ident),+ at $index:expr => $($res:ident),+) => {
+            paste! {
+                impl<$($ph),+> TupleRotateRightExact<$index> for ($($ph,)+) {
+                    type Output = ($($res,)+);
+
+                    fn rotate_right(self) -> Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        ($([< $res:lower >],)+)
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! __impl_tuple_swap {
+        ($($ph:ident),+) => {
+            impl<$($ph),+> TupleSwap for ($($ph,)+) {}
+        };
+    }
+
+    macro_rules! __impl_tuple_swap_exact {
+        ($($ph:ident),+ at $i:expr, $j:expr => $($res:ident),+) => {
+            paste! {
+                impl<$($ph),+> TupleSwapExact<$i, $j> for ($($ph,)+) {
+                    type Output = ($($res,)+);
+
+                    fn swap(self) -> Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        ($([< $res:lower >],)+)
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! __impl_tuple_get {
+        ($($ph:ident),+) => {
+            impl<$($ph),+> TupleGet for ($($ph,)+) {}
+        };
+    }
+
+    macro_rules! __impl_tuple_replace {
+        ($($ph:ident),+ | $tar:ident) => {
+            impl<$($ph),+, $tar> TupleReplace<$tar> for ($($ph,)+) {}
+        };
+    }
+
+    macro_rules! __impl_tuple_get_exact {
+        ($($ph:ident),+ | $tar:ident at $index:expr => $($res:ident),+) => {
+            paste! {
+                impl<$($ph),+> TupleGetExact<$index> for ($($ph,)+) {
+                    type Output = $tar;
+                    type Replaced<Z> = ($($res,)+);
+
+                    #[allow(unused_variables)]
+                    fn get(&self) -> &Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        [< $tar:lower >]
+                    }
+
+                    #[allow(unused_variables)]
+                    fn get_mut(&mut self) -> &mut Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        [< $tar:lower >]
+                    }
+
+                    fn replace<Z>(self, val: Z) -> (Self::Replaced<Z>, Self::Output) {
+                        let ($([< $ph:lower >],)+) = self;
+                        let old = [< $tar:lower >];
+                        let z = val;
+                        (($([< $res:lower >],)+), old)
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! __impl_tuple_apply {
+        ($($ph:ident),+) => {
+            paste! {
+                impl<$($ph),+, Func, R> TupleApply<Func> for ($($ph,)+)
+                where
+                    Func: FnOnce($($ph),+) -> R,
+                {
+                    type Output = R;
+
+                    fn apply(self, func: Func) -> Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        func($([< $ph:lower >]),+)
+                    }
+                }
+
+                impl<$($ph),+, Func, R> TupleApplyRef<Func> for ($($ph,)+)
+                where
+                    Func: FnOnce($(&$ph),+) -> R,
+                {
+                    type Output = R;
 
-    #[cfg(feature = "long-tuple-impl")]
-    mod __long_tuple_impl {
-        use paste::paste;
-    
-        use super::super::{
-            TupleConcat, TupleInsert, TupleInsertExact, TupleRemove, TupleRemoveExact, TupleZip,
+                    fn apply_ref(&self, func: Func) -> Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        func($([< $ph:lower >]),+)
+                    }
+                }
+
+                impl<$($ph),+, Func, R> TupleApplyMut<Func> for ($($ph,)+)
+                where
+                    Func: FnOnce($(&mut $ph),+) -> R,
+                {
+                    type Output = R;
+
+                    fn apply_mut(&mut self, func: Func) -> Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        func($([< $ph:lower >]),+)
+                    }
+                }
+
+                impl<$($ph),+, Func, R> FnExt<($($ph,)+)> for Func
+                where
+                    Func: Fn($($ph),+) -> R,
+                {
+                    type Output = R;
+
+                    fn tupled(self) -> impl Fn(($($ph,)+)) -> Self::Output {
+                        move |($([< $ph:lower >],)+)| self($([< $ph:lower >]),+)
+                    }
+                }
+            }
         };
+    }
+
+    macro_rules! __impl_tuple_transpose {
+        ($($ph:ident),+) => {
+            paste! {
+                impl<$($ph),+> TupleTranspose for ($(Option<$ph>,)+) {
+                    type Output = Option<($($ph,)+)>;
+
+                    fn transpose(self) -> Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        Some(($([< $ph:lower >]?,)+))
+                    }
+                }
+
+                impl<$($ph),+, Error> TupleTranspose for ($(Result<$ph, Error>,)+) {
+                    type Output = Result<($($ph,)+), Error>;
+
+                    fn transpose(self) -> Self::Output {
+                        let ($([< $ph:lower >],)+) = self;
+                        Ok(($([< $ph:lower >]?,)+))
+                    }
+                }
 
-        include!(concat!(
-            env!("CARGO_MANIFEST_DIR"),
-            "/src/tuple_long_impl.rs"
-        ));
+                impl<$($ph),+, $([< E $ph >]),+> TupleTransposeAll
+                    for ($(Result<$ph, [< E $ph >]>,)+)
+                {
+                    type Output = Result<($($ph,)+), ($(Option<[< E $ph >]>,)+)>;
+
+                    fn transpose_all(self) -> Self::Output {
+                        match self {
+                            ($(Ok([< $ph:lower >]),)+) => Ok(($([< $ph:lower >],)+)),
+                            ($([< $ph:lower >],)+) => Err(($([< $ph:lower >].err(),)+)),
+                        }
+                    }
+                }
+            }
+        };
     }
+
+    include!(concat!(env!("OUT_DIR"), "/tuple_impl.rs"));
 }
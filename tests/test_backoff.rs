@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use rs_std_ext::time::{retry, retry_with, Backoff, DurationNumExt, Jitter, SplitMix64};
+
+#[test]
+fn test_backoff_schedules() {
+    let ms = |v: &[u64]| v.iter().map(|v| v.milliseconds()).collect::<Vec<_>>();
+
+    let delays: Vec<_> = Backoff::constant(10.milliseconds()).take(3).collect();
+    assert_eq!(delays, ms(&[10, 10, 10]));
+
+    let delays: Vec<_> = Backoff::linear(10.milliseconds(), 5.milliseconds())
+        .cap(22.milliseconds())
+        .max_retries(4)
+        .collect();
+    assert_eq!(delays, ms(&[10, 15, 20, 22]));
+
+    let delays: Vec<_> = Backoff::fibonacci(10.milliseconds()).take(6).collect();
+    assert_eq!(delays, ms(&[10, 10, 20, 30, 50, 80]));
+
+    let delays: Vec<_> = Backoff::exponential(1.seconds(), 10.0)
+        .budget(111.seconds())
+        .collect();
+    assert_eq!(delays, [1.seconds(), 10.seconds(), 100.seconds()]);
+
+    let mut huge = Backoff::exponential(Duration::MAX, 2.0);
+    assert_eq!(huge.next(), Some(Duration::MAX));
+    assert_eq!(huge.next(), None);
+}
+
+#[test]
+fn test_backoff_jitter() {
+    let mut values = [0.0, 0.5, 0.999].into_iter().cycle();
+    let delays: Vec<_> = Backoff::constant(100.milliseconds())
+        .jitter(Jitter::Equal)
+        .jitter_source(move || values.next().unwrap())
+        .take(3)
+        .collect();
+    assert_eq!(delays[0], 50.milliseconds());
+    assert_eq!(delays[1], 75.milliseconds());
+    assert!(delays[2] < 100.milliseconds() && delays[2] > 99.milliseconds());
+
+    let decorrelated = |seed| {
+        Backoff::decorrelated_jitter(10.milliseconds())
+            .cap(1.seconds())
+            .jitter_source(SplitMix64::new(seed))
+            .take(50)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(decorrelated(7), decorrelated(7));
+    assert_ne!(decorrelated(7), decorrelated(8));
+    assert!(decorrelated(7)
+        .iter()
+        .all(|d| *d >= 10.milliseconds() && *d <= 1.seconds()));
+}
+
+#[test]
+fn test_backoff_default_jitter_is_reproducible() {
+    let schedule = || {
+        Backoff::exponential(1.seconds(), 2.0)
+            .jitter(Jitter::Full)
+            .take(8)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(schedule(), schedule());
+
+    let decorrelated = || {
+        Backoff::decorrelated_jitter(10.milliseconds())
+            .take(8)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(decorrelated(), decorrelated());
+
+    let from_entropy = || {
+        Backoff::exponential(1.seconds(), 2.0)
+            .jitter(Jitter::Full)
+            .jitter_source(SplitMix64::from_entropy())
+            .take(8)
+            .collect::<Vec<_>>()
+    };
+    assert_ne!(from_entropy(), from_entropy());
+}
+
+#[test]
+fn test_retry() {
+    let mut slept = Vec::new();
+    let mut calls = 0;
+    let result: Result<(), _> = retry_with(
+        Backoff::exponential(1.seconds(), 2.0).max_retries(3),
+        |d| slept.push(d),
+        || {
+            calls += 1;
+            Err(calls)
+        },
+    );
+    assert_eq!(result, Err(vec![1, 2, 3, 4]));
+    assert_eq!(slept, [1.seconds(), 2.seconds(), 4.seconds()]);
+
+    let result = retry_with(Backoff::constant(1.seconds()), |_| {}, || Ok::<_, ()>(42));
+    assert_eq!(result, Ok(42));
+}
+
+#[test]
+fn test_retry_max_retries_counts_calls() {
+    for retries in 0..4 {
+        let mut calls = 0;
+        let result: Result<(), _> = retry(
+            Backoff::constant(Duration::ZERO).max_retries(retries),
+            || {
+                calls += 1;
+                Err(())
+            },
+        );
+        assert_eq!(calls, retries + 1);
+        assert_eq!(result.unwrap_err().len(), retries + 1);
+    }
+
+    let mut backoff = Backoff::constant(1.seconds()).max_retries(2);
+    assert_eq!(backoff.by_ref().count(), 2);
+    assert_eq!(backoff.retries(), 2);
+}
+
+#[test]
+fn test_retry_max_attempts_counts_calls() {
+    for attempts in 1..4 {
+        let mut calls = 0;
+        let result: Result<(), _> = retry(
+            Backoff::constant(Duration::ZERO).max_attempts(attempts),
+            || {
+                calls += 1;
+                Err(())
+            },
+        );
+        assert_eq!(calls, attempts);
+        assert_eq!(result.unwrap_err().len(), attempts);
+    }
+
+    let mut calls = 0;
+    let _ = retry(Backoff::constant(Duration::ZERO).max_attempts(0), || {
+        calls += 1;
+        Err::<(), _>(())
+    });
+    assert_eq!(calls, 1);
+}
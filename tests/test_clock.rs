@@ -0,0 +1,96 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use rs_std_ext::time::{
+    retry_on, retry_with, Backoff, Clock, Deadline, DurationNumExt, ManualClock, SystemClock,
+    Timestamp,
+};
+
+#[test]
+fn test_manual_clock() {
+    let clock = ManualClock::new();
+    let start = clock.now();
+    assert_eq!(clock.elapsed(start), Duration::ZERO);
+
+    clock.advance(5.seconds());
+    assert_eq!(clock.now() - start, 5.seconds());
+    assert_eq!(start - clock.now(), Duration::ZERO);
+    assert_eq!(start.checked_duration_since(clock.now()), None);
+
+    // the clock never moves backwards
+    clock.advance_to(start);
+    assert_eq!(clock.elapsed(start), 5.seconds());
+    clock.advance_to(start + 1.minutes());
+    assert_eq!(clock.now(), start + 60.seconds());
+
+    assert_eq!(start.checked_sub(1.nanoseconds()), None);
+    assert_eq!((start + 1.seconds()).checked_sub(1.seconds()), Some(start));
+
+    // retry schedules run without actually sleeping
+    let shared = Arc::new(clock.clone());
+    let begin = shared.now();
+    let result: Result<(), _> = retry_with(
+        Backoff::exponential(1.seconds(), 2.0).max_retries(3),
+        |d| shared.sleep(d),
+        || Err(shared.elapsed(begin)),
+    );
+    assert_eq!(
+        result,
+        Err(vec![0.seconds(), 1.seconds(), 3.seconds(), 7.seconds()])
+    );
+    assert_eq!(clock.elapsed(begin), 7.seconds());
+}
+
+#[test]
+fn test_clock_deadline_and_retry() {
+    let clock = ManualClock::new();
+    let start = clock.now();
+
+    let deadline = Deadline::after_on(&clock, 10.seconds());
+    assert_eq!(deadline.instant(), start + 10.seconds());
+    assert_eq!(deadline.remaining_on(&clock), 10.seconds());
+
+    clock.advance(2.seconds());
+    let sub = deadline.sub_deadline_on(&clock, 0.5);
+    assert_eq!(sub, Deadline::at(start + 6.seconds()));
+    assert_eq!(
+        deadline.reserve_on(&clock, 3.seconds()),
+        Deadline::at(start + 7.seconds())
+    );
+
+    sub.sleep_until_on(&clock);
+    assert_eq!(clock.now(), start + 6.seconds());
+    assert!(sub.is_expired_on(&clock));
+    assert!(!deadline.is_expired_on(&clock));
+
+    let far = Deadline::after_on(&clock, Duration::MAX);
+    assert!(!far.is_expired_on(&clock));
+
+    let mut calls = 0;
+    let result = retry_on(
+        &clock,
+        Backoff::constant(1.seconds()).max_retries(5),
+        || {
+            calls += 1;
+            if deadline.is_expired_on(&clock) {
+                Ok(calls)
+            } else {
+                Err(())
+            }
+        },
+    );
+    assert_eq!(result, Ok(5));
+    assert_eq!(clock.now(), start + 10.seconds());
+}
+
+#[test]
+fn test_system_clock() {
+    fn now(clock: &dyn Clock) -> Timestamp {
+        clock.now()
+    }
+
+    let a = now(&SystemClock);
+    SystemClock.sleep(1.milliseconds());
+    let b = now(&Box::new(SystemClock));
+    assert!(b - a >= 1.milliseconds());
+}
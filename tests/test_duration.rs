@@ -1,13 +1,17 @@
 use std::time::Duration;
 
+#[cfg(feature = "crate-num")]
 use num::{bigint::Sign, BigInt};
 
 #[test]
 fn test_duration_fallable() {
     use rs_std_ext::time::DurationNumExtFallible;
 
-    let d = BigInt::new(Sign::Plus, vec![10]).milliseconds();
-    assert_eq!(d, Some(Duration::from_millis(10)));
+    #[cfg(feature = "crate-num")]
+    {
+        let d = BigInt::new(Sign::Plus, vec![10]).milliseconds();
+        assert_eq!(d, Some(Duration::from_millis(10)));
+    }
 
     let d = 10u8.seconds();
     assert_eq!(d, Some(Duration::from_secs(10)));
@@ -25,3 +29,421 @@ fn test_duration_infallable() {
     let d = 10u8.seconds();
     assert_eq!(d, Duration::from_secs(10));
 }
+
+#[test]
+fn test_duration_display() {
+    use rs_std_ext::time::{DurationExt, TimeUnit, UnitStyle};
+
+    let d = Duration::new(93_784, 500_000_000);
+    assert_eq!(d.display_human().to_string(), "1d 2h 3m 4.5s");
+    assert_eq!(d.display_compact().to_string(), "26:03:04.500");
+    assert_eq!(d.display_iso8601().to_string(), "P1DT2H3M4.5S");
+
+    let d = Duration::from_secs(3600);
+    assert_eq!(d.display_human().to_string(), "1h");
+    assert_eq!(
+        d.display_human().unit_style(UnitStyle::Long).to_string(),
+        "1 hour"
+    );
+    assert_eq!(d.display_iso8601().to_string(), "PT1H");
+    assert_eq!(
+        d.display_compact().largest_unit(TimeUnit::Day).to_string(),
+        "00:01:00:00.000"
+    );
+
+    let d = Duration::new(59, 999_600_000);
+    assert_eq!(d.display_human().precision(Some(3)).to_string(), "1m");
+    assert_eq!(format!("{:.3}", d.display_compact()), "00:01:00.000");
+
+    let d = Duration::from_nanos(1_500);
+    assert_eq!(d.display_human().to_string(), "0.0000015s");
+    assert_eq!(
+        d.display_human()
+            .smallest_unit(TimeUnit::Nanosecond)
+            .to_string(),
+        "1µs 500ns"
+    );
+    assert_eq!(d.display_iso8601().to_string(), "PT0.0000015S");
+
+    let d = Duration::from_secs(1);
+    let weeks = d.display_human().smallest_unit(TimeUnit::Week);
+    assert_eq!(weeks.to_string(), "0.000001653439153w");
+    assert_eq!(format!("{:.20}", weeks), "0.000001653439153w");
+    assert_eq!(
+        Duration::new(604_799, 999_999_999)
+            .display_human()
+            .smallest_unit(TimeUnit::Week)
+            .precision(Some(3))
+            .to_string(),
+        "1.000w"
+    );
+
+    let d = Duration::from_secs(86_400 + 90);
+    assert_eq!(
+        d.display_iso8601()
+            .smallest_unit(TimeUnit::Minute)
+            .to_string(),
+        "P1DT1.5M"
+    );
+    assert_eq!(
+        Duration::MAX.display_human().to_string(),
+        "213503982334601d 7h 15.999999999s"
+    );
+}
+
+#[test]
+fn test_duration_display_padding() {
+    use rs_std_ext::time::{DurationExt, TimeUnit};
+
+    let d = Duration::from_secs(90);
+    assert_eq!(format!("{:>10}", d.display_human()), "    1m 30s");
+    assert_eq!(format!("{:10}|", d.display_human()), "1m 30s    |");
+    assert_eq!(format!("{:*^10}", d.display_human()), "**1m 30s**");
+    assert_eq!(format!("{:>12.1}", d.display_compact()), "  00:01:30.0");
+    assert_eq!(format!("{:3}", d.display_iso8601()), "PT1M30S");
+
+    // the width counts characters, not bytes
+    let d = Duration::from_micros(3);
+    assert_eq!(
+        format!(
+            "{:>5}",
+            d.display_human().smallest_unit(TimeUnit::Microsecond)
+        ),
+        "  3µs"
+    );
+}
+
+#[test]
+fn test_duration_parse() {
+    use rs_std_ext::time::extfn::parse_duration;
+    use rs_std_ext::time::{DurationExt, DurationFromStrExt, ParseDurationErrorKind};
+
+    assert_eq!(
+        parse_duration("1d 2h 3m 4.5s"),
+        Ok(Duration::new(93_784, 500_000_000))
+    );
+    assert_eq!(
+        parse_duration(" 1 day 2 hours "),
+        Ok(Duration::from_secs(93_600))
+    );
+    assert_eq!(parse_duration("1h30m"), Ok(Duration::from_secs(5_400)));
+    assert_eq!(parse_duration("1.5µs 2ns"), Ok(Duration::from_nanos(1_502)));
+    assert_eq!(parse_duration("2us"), Ok(Duration::from_micros(2)));
+    assert_eq!(parse_duration("0.1ms"), Ok(Duration::from_micros(100)));
+    assert_eq!(
+        parse_duration("P1DT2H3M4.5S"),
+        Ok(Duration::new(93_784, 500_000_000))
+    );
+    assert_eq!(parse_duration("PT0.5H"), Ok(Duration::from_secs(1_800)));
+    assert_eq!(
+        parse_duration("26:03:04.500"),
+        Ok(Duration::new(93_784, 500_000_000))
+    );
+    assert_eq!(parse_duration("03:04"), Ok(Duration::from_secs(184)));
+
+    let d = Duration::new(1_234_567, 890_000_000);
+    assert_eq!(d.display_human().to_string().parse_duration(), Ok(d));
+    assert_eq!(d.display_compact().to_string().parse_duration(), Ok(d));
+    assert_eq!(d.display_iso8601().to_string().parse_duration(), Ok(d));
+
+    let kind = |s: &str| parse_duration(s).map_err(|e| (e.kind().clone(), e.position()));
+    assert_eq!(kind(""), Err((ParseDurationErrorKind::Empty, 0)));
+    assert_eq!(kind("10"), Err((ParseDurationErrorKind::MissingUnit, 2)));
+    assert_eq!(
+        kind("1h x"),
+        Err((ParseDurationErrorKind::InvalidNumber, 3))
+    );
+    assert_eq!(
+        kind("1h -5s"),
+        Err((ParseDurationErrorKind::InvalidNumber, 3))
+    );
+    assert_eq!(
+        kind("P1Y"),
+        Err((ParseDurationErrorKind::UnknownUnit("Y".into()), 2))
+    );
+    assert_eq!(
+        kind("PT1S1M"),
+        Err((ParseDurationErrorKind::InvalidFormat, 5))
+    );
+    assert_eq!(
+        kind("00:61:00"),
+        Err((ParseDurationErrorKind::FieldOutOfRange, 3))
+    );
+    assert_eq!(
+        kind("1:2"),
+        Err((ParseDurationErrorKind::InvalidNumber, 2))
+    );
+    assert_eq!(
+        kind("1h 1h"),
+        Err((ParseDurationErrorKind::InvalidFormat, 4))
+    );
+    assert_eq!(
+        kind("1h 1."),
+        Err((ParseDurationErrorKind::InvalidNumber, 3))
+    );
+    assert_eq!(
+        kind("PT1.5H30M"),
+        Err((ParseDurationErrorKind::InvalidFormat, 6))
+    );
+    assert_eq!(
+        kind("P1.5DT1H"),
+        Err((ParseDurationErrorKind::InvalidFormat, 6))
+    );
+    assert_eq!(
+        kind("18446744073709551616s"),
+        Err((ParseDurationErrorKind::Overflow, 0))
+    );
+}
+
+#[test]
+fn test_signed_duration() {
+    use rs_std_ext::time::{SignedDuration, SignedDurationNumExt};
+
+    let a = (-5i32).seconds();
+    let b = 3u8.seconds();
+    assert_eq!(a, SignedDuration::negative(Duration::from_secs(5)));
+    assert_eq!(a + b, (-2i8).seconds());
+    assert_eq!(b - a, 8i64.seconds());
+    assert_eq!(-a, 5.0f32.seconds());
+    assert_eq!(a * -2, 10u16.seconds());
+    assert_eq!(a / 2, (-2500i32).milliseconds());
+    assert_eq!((-0i32).seconds(), SignedDuration::ZERO);
+    assert_eq!((-0.0f64).seconds().signum(), 0);
+    assert!(a < SignedDuration::ZERO && SignedDuration::ZERO < b);
+    assert_eq!(a.as_nanos(), -5_000_000_000);
+    assert_eq!(SignedDuration::from_nanos(a.as_nanos()), Some(a));
+
+    assert_eq!(Duration::try_from(b), Ok(Duration::from_secs(3)));
+    assert!(Duration::try_from(a).is_err());
+
+    assert_eq!("-1m 30s".parse(), Ok((-90i32).seconds()));
+    assert_eq!("+PT1S".parse(), Ok(1i32.seconds()));
+    assert_eq!("-1x".parse::<SignedDuration>().unwrap_err().position(), 2);
+
+    assert_eq!(a.to_string(), "-5s");
+    assert_eq!(format!("{:.1}", (-1_250i32).milliseconds()), "-1.3s");
+    assert_eq!(format!("{:>6}|{:<4}|", a, b), "   -5s|3s  |");
+    for d in [a, b, (-93_784_500i64).milliseconds(), SignedDuration::ZERO] {
+        assert_eq!(d.to_string().parse(), Ok(d));
+    }
+    assert_eq!(
+        SignedDuration::MIN.to_string().parse(),
+        Ok(SignedDuration::MIN)
+    );
+
+    let total: SignedDuration = [a, b, b].iter().sum();
+    assert_eq!(total, 1i32.seconds());
+    assert_eq!(SignedDuration::MIN.saturating_sub(b), SignedDuration::MIN);
+}
+
+#[test]
+fn test_duration_round() {
+    use rs_std_ext::time::{DurationExt, DurationParts, RoundingMode, TimeUnit};
+
+    let modes = [
+        RoundingMode::TowardZero,
+        RoundingMode::AwayFromZero,
+        RoundingMode::HalfUp,
+        RoundingMode::HalfDown,
+        RoundingMode::HalfEven,
+    ];
+    let round = |millis: u64| {
+        modes.map(|mode| {
+            Duration::from_millis(millis)
+                .round_with(TimeUnit::Second, mode)
+                .unwrap()
+                .as_secs()
+        })
+    };
+    assert_eq!(round(1_500), [1, 2, 2, 1, 2]);
+    assert_eq!(round(2_500), [2, 3, 3, 2, 2]);
+    assert_eq!(round(2_400), [2, 3, 2, 2, 2]);
+    assert_eq!(round(2_600), [2, 3, 3, 3, 3]);
+    assert_eq!(round(3_000), [3, 3, 3, 3, 3]);
+
+    let d = Duration::from_secs(3_599);
+    assert_eq!(d.round_to(TimeUnit::Hour), Duration::from_secs(3_600));
+    assert_eq!(d.floor_to(TimeUnit::Hour), Duration::ZERO);
+    assert_eq!(d.round_to(Duration::ZERO), d);
+    assert_eq!(Duration::MAX.floor_to(TimeUnit::Day).as_secs() % 86_400, 0);
+
+    let parts = Duration::MAX.decompose();
+    assert_eq!(parts.to_duration(), Some(Duration::MAX));
+    assert_eq!(parts.get(TimeUnit::Hour), 7);
+    assert_eq!(DurationParts::default().to_duration(), Some(Duration::ZERO));
+}
+
+#[test]
+fn test_duration_scaled_arithmetic() {
+    use rs_std_ext::time::DurationExt;
+
+    let d = Duration::from_millis(1_500);
+    assert_eq!(d.checked_mul_f64(2.0), Some(Duration::from_secs(3)));
+    assert_eq!(d.checked_mul_f64(-0.0), Some(Duration::ZERO));
+    assert_eq!(d.checked_mul_f64(f64::NAN), None);
+    assert_eq!(d.saturating_mul_f64(-1.0), Duration::ZERO);
+    assert_eq!(Duration::MAX.saturating_mul_f64(1.5), Duration::MAX);
+
+    // exact up to the nanosecond, unlike a round trip through `as_secs_f64`
+    let large = Duration::new(1_000_000_000, 1);
+    assert_ne!(Duration::from_secs_f64(large.as_secs_f64()), large);
+    assert_eq!(large.checked_mul_f64(1.0), Some(large));
+    assert_eq!(
+        large.checked_mul_f64(2.0),
+        Some(Duration::new(2_000_000_000, 2))
+    );
+    assert_eq!(Duration::MAX.checked_mul_f64(1.0), Some(Duration::MAX));
+    // the remaining 0.75ns are rounded up
+    assert_eq!(
+        Duration::MAX.saturating_mul_f64(0.25),
+        Duration::from_secs(4_611_686_018_427_387_904)
+    );
+    assert_eq!(
+        Duration::from_nanos(1).checked_mul_f64(1e20),
+        Some(Duration::from_secs(100_000_000_000))
+    );
+    assert_eq!(
+        Duration::MAX.checked_mul_f64(f64::MIN_POSITIVE),
+        Some(Duration::ZERO)
+    );
+    // ties are rounded to even
+    assert_eq!(
+        Duration::from_nanos(3).checked_mul_f64(0.5),
+        Some(Duration::from_nanos(2))
+    );
+    assert_eq!(
+        Duration::from_nanos(5).checked_mul_f64(0.5),
+        Some(Duration::from_nanos(2))
+    );
+
+    assert_eq!(
+        d.checked_div_duration(Duration::from_millis(500)),
+        Some(3.0)
+    );
+
+    assert_eq!(d.mul_ratio(2, 3), Some(Duration::from_secs(1)));
+    assert_eq!(Duration::MAX.mul_ratio(2, 1), None);
+    assert_eq!(Duration::MAX.mul_ratio(1, 2), Some(Duration::MAX / 2));
+
+    assert_eq!(Duration::ZERO.lerp(Duration::MAX, 1.0), Duration::MAX);
+    assert_eq!(Duration::MAX.lerp(Duration::ZERO, 1.0), Duration::ZERO);
+    assert_eq!(d.lerp(Duration::ZERO, f64::NAN), d);
+
+    assert_eq!(
+        d.clamp_between(Duration::ZERO, Duration::from_secs(1)),
+        Duration::from_secs(1)
+    );
+}
+
+#[cfg(feature = "crate-num")]
+#[test]
+fn test_duration_div_duration_ratio() {
+    use num::rational::Ratio;
+    use rs_std_ext::time::DurationExt;
+
+    let d = Duration::from_millis(1_500);
+    assert_eq!(
+        d.checked_div_duration_ratio(Duration::from_secs(1)),
+        Some(Ratio::new(3, 2))
+    );
+    assert_eq!(d.checked_div_duration_ratio(Duration::ZERO), None);
+}
+
+#[test]
+fn test_duration_weeks_and_exact_constructors() {
+    use rs_std_ext::time::{DurationFromStrExt, TimeUnit};
+
+    {
+        use rs_std_ext::time::DurationNumExt;
+
+        assert_eq!(1u8.weeks(), Duration::from_secs(604_800));
+        assert_eq!(1.5f64.weeks(), Duration::from_secs(907_200));
+        assert_eq!(TimeUnit::Week.as_duration(), 7u8.days());
+
+        assert_eq!("2w".parse_duration(), Ok(Duration::from_secs(1_209_600)));
+        assert_eq!("1 week 1d".parse_duration(), Ok(8u8.days()));
+        assert_eq!("P1W2D".parse_duration(), Ok(9u8.days()));
+        assert!("P1D1W".parse_duration().is_err());
+        // the longest fraction of the largest unit does not overflow
+        assert_eq!(
+            "0.999999999999999999999999w".parse_duration(),
+            Ok(Duration::new(604_799, 999_999_999))
+        );
+
+        // floats are converted exactly, not through a lossy multiplication
+        assert_eq!(0.1f64.milliseconds(), Duration::from_micros(100));
+        assert_eq!(1e-9f64.seconds(), Duration::from_nanos(1));
+    }
+
+    {
+        use rs_std_ext::time::DurationNumExtFallible;
+
+        assert_eq!((-1.0f64).seconds(), None);
+        assert_eq!(f64::INFINITY.seconds(), None);
+    }
+
+    {
+        use rs_std_ext::time::{SignedDuration, SignedDurationNumExt};
+
+        assert_eq!(
+            SignedDurationNumExt::weeks(&-2i32),
+            SignedDuration::negative(Duration::from_secs(1_209_600))
+        );
+    }
+}
+
+#[cfg(feature = "crate-num")]
+#[test]
+fn test_duration_rational_constructors() {
+    use num::rational::Ratio;
+    use num::BigRational;
+
+    {
+        use rs_std_ext::time::DurationNumExtFallible;
+
+        assert_eq!(
+            Ratio::new(1u64, 3).seconds(),
+            Some(Duration::new(0, 333_333_333))
+        );
+        assert_eq!(
+            BigRational::new(1.into(), 7.into()).hours(),
+            Some(Duration::new(514, 285_714_285))
+        );
+        assert_eq!(BigRational::new((-1).into(), 2.into()).seconds(), None);
+    }
+
+    {
+        use rs_std_ext::time::{SignedDuration, SignedDurationNumExtFallible};
+
+        assert_eq!(
+            BigRational::new((-1).into(), 2.into()).seconds(),
+            Some(SignedDuration::negative(Duration::from_millis(500)))
+        );
+    }
+}
+
+#[test]
+fn test_duration_literal() {
+    use rs_std_ext::duration;
+    use rs_std_ext::time::extfn;
+
+    const TIMEOUT: Duration = duration!(1h 30m 5s 250ms);
+    const SHORT: Option<Duration> = extfn::checked_from_millis(90_000);
+
+    assert_eq!(TIMEOUT, Duration::from_millis(5_405_250));
+    assert_eq!(SHORT, Some(Duration::from_secs(90)));
+    assert_eq!(duration!(1h30m), Duration::from_secs(5_400));
+    assert_eq!(duration!(1_000 ms), Duration::from_secs(1));
+    assert_eq!(duration!(0.5us 1ns), Duration::from_nanos(501));
+    assert_eq!(duration!(1 day 2 hours), Duration::from_secs(93_600));
+    assert_eq!(duration!(0.1s), Duration::from_millis(100));
+    assert_eq!(duration!(18446744073709551615s 999999999ns), Duration::MAX);
+    assert_eq!(
+        extfn::checked_from_nanos(1_500_000_000),
+        Some(Duration::new(1, 500_000_000))
+    );
+    assert_eq!(
+        extfn::checked_from_micros(u64::MAX),
+        Some(Duration::from_micros(u64::MAX))
+    );
+}
@@ -0,0 +1,72 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use rs_std_ext::time::{Clock, DurationNumExt, Every, ManualClock, MissedTicks};
+
+#[test]
+fn test_every_alignment() {
+    let every = Every::new(15.minutes());
+    let ticks: Vec<_> = every.starting(7.minutes()).upcoming().take(2).collect();
+    assert_eq!(ticks, [7.minutes(), 22.minutes()]);
+
+    let aligned = every.aligned_to(5.minutes());
+    let ticks: Vec<_> = aligned.starting(7.minutes()).upcoming().take(2).collect();
+    assert_eq!(ticks, [20.minutes(), 35.minutes()]);
+    // a start on the alignment fires immediately
+    assert_eq!(aligned.starting(5.minutes()).next_tick(), Some(5.minutes()));
+    // offsets larger than the period wrap around
+    assert_eq!(
+        every
+            .aligned_to(20.minutes())
+            .starting(Duration::ZERO)
+            .next_tick(),
+        Some(5.minutes())
+    );
+
+    // a start before the unix epoch keeps the wall-clock alignment
+    let quarter = every.aligned_to(0.seconds());
+    let ticks: Vec<_> = quarter
+        .starting(UNIX_EPOCH - 20.minutes())
+        .upcoming()
+        .take(2)
+        .collect();
+    assert_eq!(ticks, [UNIX_EPOCH - 15.minutes(), UNIX_EPOCH]);
+    assert_eq!(
+        quarter.starting(UNIX_EPOCH - 7.minutes()).next_tick(),
+        Some(UNIX_EPOCH)
+    );
+
+    let end = Every::new(1.seconds()).starting(Duration::MAX - 1.seconds());
+    assert_eq!(end.upcoming().count(), 2);
+}
+
+#[test]
+fn test_every_missed_ticks() {
+    let policies = [
+        (MissedTicks::Skip, [0, 10, 20, 40]),
+        (MissedTicks::Burst, [0, 10, 20, 30]),
+        (MissedTicks::Delay, [0, 10, 20, 45]),
+    ];
+    for (policy, expected) in policies {
+        let clock = ManualClock::new();
+        let start = clock.now();
+        let mut ticker = Every::new(10.seconds()).on_missed(policy).starting(start);
+
+        let mut fired = vec![];
+        for advance in [0, 10, 25, 10] {
+            clock.advance(Duration::from_secs(advance));
+            while let Some(tick) = ticker.poll(clock.now()) {
+                fired.push((tick - start).as_secs());
+            }
+        }
+        assert_eq!(fired[..4], expected, "{policy:?}");
+    }
+
+    let clock = ManualClock::new();
+    let start = clock.now();
+    let mut ticker = Every::new(1.seconds()).starting(start);
+    for _ in 0..3 {
+        ticker.wait_on(&clock);
+    }
+    assert_eq!(clock.now() - start, 2.seconds());
+    assert_eq!(ticker.remaining(clock.now()), 1.seconds());
+}
@@ -0,0 +1,76 @@
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use rs_std_ext::time::{InstantExt, SignedDuration, SystemTimeExt};
+
+#[test]
+fn test_instant_ext() {
+    let now = Instant::now();
+    let later = now + Duration::from_secs(10);
+
+    assert_eq!(
+        later.elapsed_since_checked(now),
+        Some(Duration::from_secs(10))
+    );
+    assert_eq!(now.elapsed_since_checked(later), None);
+    assert_eq!(later.saturating_add(Duration::ZERO), later);
+    assert_eq!(now.saturating_add(Duration::from_secs(10)), later);
+    assert_eq!(later.saturating_sub(Duration::from_secs(10)), now);
+    let step = Duration::from_millis(1500);
+    assert_eq!(now.saturating_add(step), now.checked_add(step).unwrap());
+    assert_eq!(later.saturating_sub(step), later.checked_sub(step).unwrap());
+
+    let far = now.saturating_add(Duration::MAX);
+    assert!(far > later);
+    assert!(far.checked_add(Duration::from_nanos(1)).is_none());
+
+    assert!(now.has_passed());
+    assert!(!later.has_passed());
+    assert!(later.deadline_remaining() <= Duration::from_secs(10));
+}
+
+#[test]
+fn test_system_time_ext() {
+    let t = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+    assert_eq!(t.unix_seconds(), 1_700_000_000);
+    assert_eq!(t.unix_millis(), 1_700_000_000_123);
+    assert_eq!(
+        SystemTime::from_unix_millis_checked(t.unix_millis()),
+        Some(t)
+    );
+    assert_eq!(
+        t.truncate_to_utc_day(),
+        UNIX_EPOCH + Duration::from_secs(1_699_920_000)
+    );
+
+    let t = UNIX_EPOCH - Duration::from_millis(1);
+    assert_eq!(t.unix_seconds(), -1);
+    assert_eq!(t.unix_millis(), -1);
+    assert_eq!(SystemTime::from_unix_offset(t.unix_offset()), Some(t));
+    assert_eq!(SystemTime::from_unix_offset(SignedDuration::MAX), None);
+    assert_eq!(SystemTime::from_unix_seconds_checked(0), Some(UNIX_EPOCH));
+}
+
+#[test]
+fn test_deadline() {
+    use rs_std_ext::time::{Deadline, DurationNumExt};
+
+    let now = Instant::now();
+    let deadline = Deadline::at(now + 10.seconds());
+    assert!(!deadline.is_expired_at(now));
+    assert!(deadline.is_expired_at(now + 10.seconds()));
+
+    let sub = deadline.sub_deadline_at(now, 0.5);
+    assert_eq!(sub.remaining_at(now), 5.seconds());
+    assert_eq!(
+        sub.sub_deadline_at(now, 0.5).remaining_at(now),
+        2500.milliseconds()
+    );
+    assert_eq!(deadline.sub_deadline_at(now, f64::NAN), Deadline::at(now));
+    assert_eq!(deadline.sub_deadline_at(now + 20.seconds(), 0.5), deadline);
+
+    let short = Deadline::after(1.milliseconds());
+    short.sleep_until();
+    assert!(short.is_expired());
+    assert_eq!(short.remaining(), Duration::ZERO);
+    assert_eq!(Instant::from(deadline), now + 10.seconds());
+}
@@ -0,0 +1,56 @@
+use std::time::{Duration, Instant};
+
+use rs_std_ext::time::{DurationNumExt, Interval, ParseDurationErrorKind, TimeOfDay};
+
+#[test]
+fn test_time_of_day() {
+    let t = TimeOfDay::with_nanos(7, 5, 3, 250_000_000).unwrap();
+    assert_eq!(
+        (t.hour(), t.minute(), t.second(), t.nanosecond()),
+        (7, 5, 3, 250_000_000)
+    );
+    assert_eq!(t.since_midnight(), Duration::new(25_503, 250_000_000));
+    assert_eq!(t.to_string(), "07:05:03.25");
+    assert_eq!("07:05:03.25".parse(), Ok(t));
+    assert_eq!(TimeOfDay::new(24, 0, 0), None);
+    assert_eq!(TimeOfDay::from_duration_since_midnight(1.days()), None);
+
+    assert_eq!(t.wrapping_sub(8.hours()).hour(), 23);
+    assert_eq!(t.wrapping_add(3.days()), t);
+    assert_eq!(t.duration_until(t), Duration::ZERO);
+    assert_eq!(TimeOfDay::MIDNIGHT.duration_until(t), t.since_midnight());
+
+    let err = "24:00".parse::<TimeOfDay>().unwrap_err();
+    assert_eq!(err.kind(), &ParseDurationErrorKind::FieldOutOfRange);
+    let err = "12:60".parse::<TimeOfDay>().unwrap_err();
+    assert_eq!(err.position(), 3);
+    assert!("12".parse::<TimeOfDay>().is_err());
+    assert!("1:00".parse::<TimeOfDay>().is_err());
+    assert!("12:00:00:00".parse::<TimeOfDay>().is_err());
+    assert!("12:00:00.".parse::<TimeOfDay>().is_err());
+}
+
+#[test]
+fn test_interval() {
+    let now = Instant::now();
+    let a = Interval::starting_at(now, 10.seconds()).unwrap();
+    let b = Interval::new(now + 10.seconds(), now + 20.seconds()).unwrap();
+    assert_eq!(a.length(), 10.seconds());
+    assert!(!a.overlaps(&b));
+    assert_eq!(a.intersection(&b), None);
+    assert_eq!(a.union(&b), Interval::new(now, now + 20.seconds()));
+    assert!(a.union(&b).unwrap().contains_interval(&a));
+    assert!(!a.contains_interval(&b));
+    assert_eq!(Interval::new(now + 1.seconds(), now), None);
+
+    let empty = Interval::new(now, now).unwrap();
+    assert!(empty.is_empty());
+    assert!(!empty.contains(&now));
+    assert_eq!(empty.steps(1.seconds()).count(), 0);
+
+    let steps: Vec<_> = a.steps(3.seconds()).map(|s| s.length()).collect();
+    assert_eq!(steps, [3.seconds(), 3.seconds(), 3.seconds(), 1.seconds()]);
+
+    let end = Interval::new(Duration::MAX - 1.seconds(), Duration::MAX).unwrap();
+    assert_eq!(end.steps(2.seconds()).count(), 1);
+}
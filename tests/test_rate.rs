@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use rs_std_ext::time::{
+    DurationNumExt, ManualClock, ParseDurationErrorKind, Rate, RateLimiter, RateNumExt, TokenBucket,
+};
+
+#[test]
+fn test_rate() {
+    let rate = 100.per(1.seconds());
+    assert_eq!(rate.per_second(), 100.0);
+    assert_eq!(Rate::new(0, 1.seconds()), None);
+    assert_eq!(Rate::new(1, Duration::ZERO), None);
+
+    assert_eq!("100/s".parse(), Ok(rate));
+    assert_eq!(" 5 / minute".parse(), Ok(5.per(1.minutes())));
+    assert_eq!("5/1m 30s".parse(), Ok(5.per(90.seconds())));
+    assert_eq!(5.per(90.seconds()).to_string(), "5/1m 30s");
+    assert_eq!(3.per(1.hours()).to_string(), "3/h");
+
+    let err = "x/s".parse::<Rate>().unwrap_err();
+    assert_eq!(err.kind(), &ParseDurationErrorKind::InvalidNumber);
+    let err = "5/2x".parse::<Rate>().unwrap_err();
+    assert_eq!(err.kind(), &ParseDurationErrorKind::UnknownUnit("x".into()));
+    assert_eq!(err.position(), 3);
+    assert!("5".parse::<Rate>().is_err());
+    assert!("5/0s".parse::<Rate>().is_err());
+}
+
+#[test]
+fn test_token_bucket() {
+    let clock = ManualClock::new();
+    let mut bucket = TokenBucket::new(3.per(1.seconds()), 3).with_clock(clock.clone());
+    assert_eq!(bucket.available(), 3);
+    assert_eq!(bucket.try_acquire(4), Err(Duration::MAX));
+    assert_eq!(bucket.try_acquire(3), Ok(()));
+    // a third of a second, rounded up
+    assert_eq!(bucket.try_acquire(1), Err(333_333_334.nanoseconds()));
+
+    clock.advance(1.seconds());
+    assert_eq!(bucket.available(), 3);
+    clock.advance(1.hours());
+    assert_eq!(bucket.available(), 3);
+}
+
+#[test]
+fn test_rate_limiter() {
+    let clock = ManualClock::new();
+    let mut fixed = RateLimiter::fixed_window(2.per(1.seconds())).with_clock(clock.clone());
+    assert_eq!(fixed.try_acquire(), Ok(()));
+    clock.advance(900.milliseconds());
+    assert_eq!(fixed.try_acquire(), Ok(()));
+    assert_eq!(fixed.try_acquire(), Err(100.milliseconds()));
+    // windows stay aligned to the first permit
+    clock.advance(2_150.milliseconds());
+    assert_eq!(fixed.try_acquire(), Ok(()));
+    assert_eq!(fixed.try_acquire(), Ok(()));
+    assert_eq!(fixed.try_acquire(), Err(950.milliseconds()));
+
+    let clock = ManualClock::new();
+    let mut gcra = RateLimiter::gcra(10.per(1.seconds()), 3).with_clock(clock.clone());
+    for _ in 0..3 {
+        assert_eq!(gcra.try_acquire(), Ok(()));
+    }
+    assert_eq!(gcra.try_acquire(), Err(100.milliseconds()));
+    clock.advance(250.milliseconds());
+    assert_eq!(gcra.try_acquire(), Ok(()));
+    assert_eq!(gcra.try_acquire(), Ok(()));
+    assert_eq!(gcra.try_acquire(), Err(50.milliseconds()));
+
+    gcra.reset();
+    assert_eq!(gcra.try_acquire(), Ok(()));
+
+    let clock = ManualClock::new();
+    let mut sliding = RateLimiter::sliding_window(2.per(1.seconds())).with_clock(clock.clone());
+    assert_eq!(sliding.try_acquire(), Ok(()));
+    clock.advance(900.milliseconds());
+    assert_eq!(sliding.try_acquire(), Ok(()));
+    clock.advance(200.milliseconds());
+    assert_eq!(sliding.try_acquire(), Ok(()));
+    assert_eq!(sliding.try_acquire(), Err(800.milliseconds()));
+}
+
+#[test]
+fn test_limiter_extreme_rate() {
+    let slow = Rate::new(1, Duration::from_secs(u64::MAX)).unwrap();
+
+    let clock = ManualClock::new();
+    let mut bucket = TokenBucket::new(slow, u64::MAX).with_clock(clock.clone());
+    assert_eq!(bucket.try_acquire(u64::MAX), Ok(()));
+    assert!(bucket.try_acquire(1).is_err());
+    clock.advance(1.seconds());
+    assert!(bucket.try_acquire(1).is_err());
+
+    let mut gcra = RateLimiter::gcra(slow, u64::MAX).with_clock(clock.clone());
+    for _ in 0..100 {
+        assert_eq!(gcra.try_acquire(), Ok(()));
+    }
+}
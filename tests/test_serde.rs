@@ -0,0 +1,69 @@
+#![cfg(feature = "serde")]
+
+use std::time::Duration;
+
+use rs_std_ext::time::DurationNumExt;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "rs_std_ext::time::serde::human")]
+    human: Duration,
+    #[serde(with = "rs_std_ext::time::serde::iso8601")]
+    iso: Duration,
+    #[serde(with = "rs_std_ext::time::serde::secs")]
+    secs: Duration,
+    #[serde(with = "rs_std_ext::time::serde::millis")]
+    millis: Duration,
+    #[serde(with = "rs_std_ext::time::serde::nanos")]
+    nanos: Duration,
+    #[serde(with = "rs_std_ext::time::serde::secs_f64")]
+    secs_f64: Duration,
+}
+
+#[test]
+fn test_serde_roundtrip() {
+    let config = Config {
+        human: Duration::new(93_784, 500_000_000),
+        iso: 90.minutes(),
+        secs: 42.seconds(),
+        millis: 1_500.milliseconds(),
+        nanos: 7.nanoseconds(),
+        secs_f64: 250.milliseconds(),
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(
+        json,
+        r#"{"human":"1d 2h 3m 4.5s","iso":"PT1H30M","secs":42,"millis":1500,"nanos":7,"secs_f64":0.25}"#
+    );
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+}
+
+#[test]
+fn test_serde_errors() {
+    #[derive(Debug, Deserialize)]
+    struct Human(#[serde(with = "rs_std_ext::time::serde::human")] Duration);
+    #[derive(Debug, Deserialize)]
+    struct Iso(#[serde(with = "rs_std_ext::time::serde::iso8601")] Duration);
+    #[derive(Debug, Deserialize)]
+    struct SecsF64(#[serde(with = "rs_std_ext::time::serde::secs_f64")] Duration);
+    #[derive(Debug, Serialize)]
+    struct Nanos(#[serde(with = "rs_std_ext::time::serde::nanos")] Duration);
+
+    assert_eq!(
+        serde_json::from_str::<Human>(r#""01:30""#).unwrap().0,
+        90.seconds()
+    );
+    let err = serde_json::from_str::<Human>(r#""1h 30x""#).unwrap_err();
+    assert!(err.to_string().contains("unknown unit"), "{err}");
+
+    assert_eq!(
+        serde_json::from_str::<Iso>(r#""PT1M""#).unwrap().0,
+        60.seconds()
+    );
+    assert!(serde_json::from_str::<Iso>(r#""1h""#).is_err());
+    assert_eq!(serde_json::from_str::<SecsF64>("3").unwrap().0, 3.seconds());
+    assert!(serde_json::from_str::<SecsF64>("-1.0").is_err());
+
+    assert!(serde_json::to_string(&Nanos(Duration::MAX)).is_err());
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use rs_std_ext::time::{DurationNumExt, DurationStats};
+
+#[test]
+fn test_stats_exact() {
+    let stats = DurationStats::new();
+    assert!(stats.is_empty());
+    assert_eq!(stats.mean(), None);
+    assert_eq!(stats.percentile(50.0), None);
+    assert_eq!(stats.sum(), Some(Duration::ZERO));
+
+    let stats: DurationStats = [3, 1, 2].iter().map(|ns| ns.nanoseconds()).collect();
+    assert!(stats.is_exact());
+    assert_eq!(stats.mean(), Some(2.nanoseconds()));
+    assert_eq!(stats.sum(), Some(6.nanoseconds()));
+    assert_eq!(stats.percentile(0.0), Some(1.nanoseconds()));
+    assert_eq!(stats.percentile(50.0), Some(2.nanoseconds()));
+    assert_eq!(stats.percentile(100.0), Some(3.nanoseconds()));
+    assert_eq!(stats.percentile(f64::NAN), None);
+
+    // nanosecond precision is kept for large values
+    let stats: DurationStats = [Duration::new(u64::MAX, 1), Duration::new(u64::MAX, 2)]
+        .into_iter()
+        .collect();
+    assert_eq!(stats.mean(), Some(Duration::new(u64::MAX, 1)));
+    assert_eq!(stats.sum(), None);
+    // the exact standard deviation is half a nanosecond, which rounds up
+    #[cfg(feature = "crate-num")]
+    assert_eq!(stats.std_dev(), Some(1.nanoseconds()));
+    #[cfg(not(feature = "crate-num"))]
+    assert_eq!(stats.std_dev(), Some(Duration::ZERO));
+
+    let stats: DurationStats = [0, 2, 4]
+        .into_iter()
+        .map(|ns| Duration::new(u64::MAX, ns))
+        .collect();
+    #[cfg(feature = "crate-num")]
+    {
+        assert_eq!(stats.variance(), Some(8.0 / 3.0));
+        assert_eq!(stats.std_dev(), Some(2.nanoseconds()));
+    }
+    #[cfg(not(feature = "crate-num"))]
+    assert!(stats.variance().is_some());
+}
+
+#[test]
+fn test_stats_histogram() {
+    let mut stats = DurationStats::new().with_exact_limit(10);
+    stats.extend((1..=10_000u64).map(|us| us.microseconds()));
+    assert!(!stats.is_exact());
+    assert_eq!(stats.count(), 10_000);
+    assert_eq!(stats.min(), Some(1.microseconds()));
+    assert_eq!(stats.max(), Some(10.milliseconds()));
+    assert_eq!(stats.mean(), Some(Duration::from_nanos(5_000_500)));
+
+    for (p, expected) in [(50.0, 5_000u64), (90.0, 9_000), (99.0, 9_900)] {
+        let value = stats.percentile(p).unwrap().as_nanos() as f64;
+        let expected = expected.microseconds().as_nanos() as f64;
+        assert!((value - expected).abs() / expected < 0.005, "p{p}: {value}");
+    }
+    assert_eq!(stats.percentile(100.0), Some(10.milliseconds()));
+
+    let mut exact: DurationStats = (1..=5u64).map(|ms| ms.milliseconds()).collect();
+    exact.merge(&stats);
+    assert!(!exact.is_exact());
+    assert_eq!(exact.count(), 10_005);
+    assert_eq!(exact.min(), Some(1.microseconds()));
+}
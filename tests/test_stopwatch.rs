@@ -0,0 +1,85 @@
+use std::cell::Cell;
+use std::time::Duration;
+
+use rs_std_ext::time::{
+    time_it, time_it_on, DurationNumExt, ManualClock, Stopwatch, TimedGuard, Timestamp,
+};
+
+#[test]
+fn test_stopwatch() {
+    let clock = ManualClock::new();
+    let mut sw = Stopwatch::<Timestamp>::default();
+    assert!(!sw.is_running());
+    clock.advance(5.milliseconds());
+    assert_eq!(sw.elapsed_on(&clock), Duration::ZERO);
+
+    sw.start_on(&clock);
+    clock.advance(5.milliseconds());
+    let first = sw.lap_on(&clock);
+    assert_eq!(first, 5.milliseconds());
+
+    // paused time is not counted
+    clock.advance(3.milliseconds());
+    let total = sw.stop_on(&clock);
+    assert_eq!(total, 8.milliseconds());
+    clock.advance(20.milliseconds());
+    assert_eq!(sw.elapsed_on(&clock), total);
+    assert_eq!(sw.split_on(&clock), total - first);
+
+    sw.start_on(&clock);
+    sw.start_on(&clock);
+    clock.advance(5.milliseconds());
+    let second = sw.lap_on(&clock);
+    assert_eq!(second, 8.milliseconds());
+    assert_eq!(sw.laps(), [first, second]);
+
+    sw.restart_on(&clock);
+    assert!(sw.is_running());
+    assert!(sw.laps().is_empty());
+    assert_eq!(sw.elapsed_on(&clock), Duration::ZERO);
+
+    let sw = Stopwatch::start_new_on(&clock);
+    clock.advance(1.seconds());
+    assert_eq!(sw.split_on(&clock), 1.seconds());
+}
+
+#[test]
+fn test_stopwatch_instant() {
+    let mut sw = Stopwatch::start_new();
+    assert!(sw.is_running());
+    let lap = sw.lap();
+    let total = sw.stop();
+    assert!(lap <= total);
+    assert_eq!(sw.elapsed(), total);
+    assert_eq!(sw.laps(), [lap]);
+}
+
+#[test]
+fn test_timed_guard() {
+    let clock = ManualClock::new();
+    let (value, took) = time_it_on(&clock, || {
+        clock.advance(5.milliseconds());
+        42
+    });
+    assert_eq!(value, 42);
+    assert_eq!(took, 5.milliseconds());
+
+    let reported = Cell::new(None);
+    {
+        let guard = TimedGuard::new_on(&clock, |d| reported.set(Some(d)));
+        clock.advance(5.milliseconds());
+        assert_eq!(guard.elapsed(), 5.milliseconds());
+        clock.advance(1.milliseconds());
+    }
+    assert_eq!(reported.get(), Some(6.milliseconds()));
+
+    let reported = Cell::new(false);
+    TimedGuard::new_on(&clock, |_| reported.set(true)).cancel();
+    assert!(!reported.get());
+
+    let (value, _) = time_it(|| 42);
+    assert_eq!(value, 42);
+    let reported = Cell::new(None);
+    drop(TimedGuard::new(|d| reported.set(Some(d))));
+    assert!(reported.get().is_some());
+}
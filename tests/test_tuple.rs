@@ -1,4 +1,9 @@
-use rs_std_ext::tuple::{TupleConcat, TupleInsert, TupleRemove, TupleZip};
+use rs_std_ext::tuple::{
+    ArrayToTuple, FnExt, TupleApply, TupleApplyMut, TupleApplyRef, TupleConcat, TupleGet,
+    TupleInsert, TupleIter, TupleMap, TupleMapNth, TuplePop, TupleRemove, TupleReplace,
+    TupleReverse, TupleRotate, TupleSplit, TupleSwap, TupleToArray, TupleTranspose,
+    TupleTransposeAll, TupleZip,
+};
 
 #[test]
 fn test_tuple_zip() {
@@ -29,3 +34,196 @@ fn test_tuple_concat() {
     let y = x.concat((10i32, 'a'));
     assert_eq!(y, (1, 2, false, 10, 'a'));
 }
+
+#[test]
+fn test_tuple_map() {
+    let x = (1u8, "foo", false);
+    let y = x.map((|a: u8| a as u32 + 1, |b: &str| b.len(), |c: bool| !c));
+    assert_eq!(y, (2u32, 3usize, true));
+}
+
+#[test]
+fn test_tuple_map_nth() {
+    let x = (1u8, "foo", false);
+    let y = x.map_nth::<1>(|b: &str| b.to_uppercase());
+    assert_eq!(y, (1, "FOO".to_string(), false));
+
+    let z = (1u8,).map_nth::<0>(|a: u8| a * 2);
+    assert_eq!(z, (2,));
+}
+
+#[cfg(feature = "long-tuple-impl")]
+#[test]
+fn test_tuple_map_long() {
+    let x = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    let y = x.map_nth::<9>(|j: i32| j * 10);
+    assert_eq!(y, (1, 2, 3, 4, 5, 6, 7, 8, 9, 100));
+
+    let double = |v: i32| v * 2;
+    let z = (1, 2, 3, 4, 5, 6).map((double, double, double, double, double, |f: i32| f));
+    assert_eq!(z, (2, 4, 6, 8, 10, 6));
+}
+
+#[test]
+fn test_tuple_array() {
+    let x = (1u8, 2, 3);
+    let y = x.into_array();
+    assert_eq!(y, [1, 2, 3]);
+    assert_eq!(y.into_tuple(), x);
+
+    assert_eq!(['a'].into_tuple(), ('a',));
+}
+
+#[test]
+fn test_tuple_iter() {
+    let mut x = ("a".to_string(), "b".to_string());
+    for s in x.iter_mut() {
+        s.push('!');
+    }
+    assert_eq!(x.iter().map(|s| s.len()).sum::<usize>(), 4);
+    assert_eq!(x.into_iter().collect::<String>(), "a!b!");
+}
+
+#[cfg(feature = "long-tuple-impl")]
+#[test]
+fn test_tuple_array_long() {
+    let x = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    assert_eq!(x.iter().sum::<i32>(), 55);
+    assert_eq!(x.into_array().into_tuple(), x);
+}
+
+#[test]
+fn test_tuple_split() {
+    let x = (1u8, 2usize, false);
+    assert_eq!(x.split_at::<1>(), ((1,), (2, false)));
+    assert_eq!(x.split_at::<3>(), ((1, 2, false), ()));
+
+    let (l, r) = x.split_at::<2>();
+    assert_eq!(l.concat(r), x);
+}
+
+#[test]
+fn test_tuple_pop() {
+    let x = (1u8, 2usize, false);
+    assert_eq!(x.first(), &1);
+    assert_eq!(x.last(), &false);
+    assert_eq!(x.pop_front(), (1, (2, false)));
+    assert_eq!(x.pop_back(), (false, (1, 2)));
+
+    assert_eq!(('a',).pop_front(), ('a', ()));
+}
+
+#[cfg(feature = "long-tuple-impl")]
+#[test]
+fn test_tuple_split_long() {
+    let x = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    assert_eq!(x.split_at::<7>(), ((1, 2, 3, 4, 5, 6, 7), (8, 9, 10)));
+    assert_eq!(x.last(), &10);
+    assert_eq!(x.pop_front().1, (2, 3, 4, 5, 6, 7, 8, 9, 10));
+}
+
+#[test]
+fn test_tuple_reorder() {
+    let x = (1u8, 2usize, false);
+    assert_eq!(x.reverse(), (false, 2, 1));
+    assert_eq!(x.rotate_left::<1>(), (2, false, 1));
+    assert_eq!(x.rotate_right::<1>(), (false, 1, 2));
+    assert_eq!(x.rotate_left::<3>(), x);
+    assert_eq!(x.swap::<0, 1>(), (2, 1, false));
+    assert_eq!(x.swap::<2, 0>(), (false, 2, 1));
+    assert_eq!(x.swap::<1, 1>(), x);
+}
+
+#[cfg(feature = "long-tuple-impl")]
+#[test]
+fn test_tuple_reorder_long() {
+    let x = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    assert_eq!(x.reverse(), (10, 9, 8, 7, 6, 5, 4, 3, 2, 1));
+    assert_eq!(x.rotate_right::<3>(), (8, 9, 10, 1, 2, 3, 4, 5, 6, 7));
+    assert_eq!(x.swap::<0, 9>(), (10, 2, 3, 4, 5, 6, 7, 8, 9, 1));
+}
+
+#[test]
+fn test_tuple_get() {
+    let mut x = (1u8, 2usize, false);
+    assert_eq!(x.get::<1>(), &2);
+
+    *x.get_mut::<2>() = true;
+    assert_eq!(x, (1, 2, true));
+
+    let (y, old) = x.replace::<0>("foo");
+    assert_eq!(y, ("foo", 2, true));
+    assert_eq!(old, 1);
+}
+
+#[cfg(feature = "long-tuple-impl")]
+#[test]
+fn test_tuple_get_long() {
+    let mut x = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    *x.get_mut::<9>() *= 10;
+    assert_eq!(x.get::<9>(), &100);
+    assert_eq!(x.replace::<5>('a').0, (1, 2, 3, 4, 5, 'a', 7, 8, 9, 100));
+}
+
+#[test]
+fn test_tuple_apply() {
+    fn describe(a: u8, b: &str, c: bool) -> String {
+        format!("{a}-{b}-{c}")
+    }
+
+    let mut x = (1u8, "foo", false);
+    assert_eq!(x.apply(describe), "1-foo-false");
+    assert_eq!(
+        x.apply_ref(|a: &u8, b: &&str, _: &bool| *a as usize + b.len()),
+        4
+    );
+
+    x.apply_mut(|a: &mut u8, _: &mut &str, c: &mut bool| {
+        *a += 1;
+        *c = true;
+    });
+    assert_eq!(x, (2, "foo", true));
+
+    let tupled = describe.tupled();
+    assert_eq!(tupled(x), "2-foo-true");
+}
+
+#[cfg(feature = "long-tuple-impl")]
+#[test]
+fn test_tuple_apply_long() {
+    let sum = |a: i32, b: i32, c: i32, d: i32, e: i32, f: i32, g: i32| a + b + c + d + e + f + g;
+    let x = (1, 2, 3, 4, 5, 6, 7);
+    assert_eq!(x.apply(sum), 28);
+    assert_eq!(sum.tupled()(x), 28);
+}
+
+#[test]
+fn test_tuple_transpose() {
+    assert_eq!(
+        (Some(1u8), Some('a'), Some(false)).transpose(),
+        Some((1, 'a', false))
+    );
+    assert_eq!((Some(1u8), None::<char>).transpose(), None);
+
+    let ok: (Result<u8, String>, Result<char, String>) = (Ok(1), Ok('a'));
+    assert_eq!(ok.clone().transpose(), Ok((1, 'a')));
+    assert_eq!(ok.transpose_all(), Ok((1, 'a')));
+
+    let err: (Result<u8, String>, Result<char, String>) = (Ok(1), Err("bad".to_string()));
+    assert_eq!(err.clone().transpose(), Err("bad".to_string()));
+    assert_eq!(err.transpose_all(), Err((None, Some("bad".to_string()))));
+}
+
+#[cfg(feature = "long-tuple-impl")]
+#[test]
+fn test_tuple_transpose_long() {
+    let x = (Some(1), Some(2), Some(3), Some(4), Some(5), Some(6));
+    assert_eq!(x.transpose(), Some((1, 2, 3, 4, 5, 6)));
+
+    let r = |v: i32| if v % 2 == 0 { Err(v) } else { Ok(v) };
+    let y = (r(1), r(3), r(5), r(4), r(7), r(6));
+    assert_eq!(
+        y.transpose_all(),
+        Err((None, None, None, Some(4), None, Some(6)))
+    );
+}
@@ -0,0 +1,24 @@
+//! Compile-time checks that every `TupleInsertExact`, `TupleRemoveExact` and `TupleConcat`
+//! implementation exists, with the expected output, for the longest tuple enabled by the features.
+
+use rs_std_ext::tuple::{TupleConcat, TupleInsertExact, TupleRemoveExact};
+
+fn insert_exact<T, const POS: usize, Z, O>()
+where
+    T: TupleInsertExact<POS, Z, Output = O>,
+{
+}
+
+fn remove_exact<T, const POS: usize, O>()
+where
+    T: TupleRemoveExact<POS, Output = O>,
+{
+}
+
+fn concat<T, U, O>()
+where
+    T: TupleConcat<U, Output = O>,
+{
+}
+
+include!(concat!(env!("OUT_DIR"), "/tuple_impl_check.rs"));